@@ -0,0 +1,26 @@
+//! Implementation of the anti-sniping defaults.
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::URef;
+
+use crate::{constants::ANTI_SNIPING_KEY_NAME, detail};
+
+/// Window before `end_time` in which a bid extends an auction, and the extension itself.
+pub type AntiSniping = (u64, u64);
+
+#[inline]
+pub(crate) fn anti_sniping_uref() -> URef {
+    detail::get_uref(ANTI_SNIPING_KEY_NAME)
+}
+
+/// Reads the default anti-sniping window and extension.
+pub(crate) fn read_anti_sniping() -> AntiSniping {
+    let uref = anti_sniping_uref();
+    storage::read(uref).unwrap_or_revert().unwrap_or_default()
+}
+
+/// Writes the default anti-sniping window and extension.
+pub(crate) fn write_anti_sniping(value: AntiSniping) {
+    let uref = anti_sniping_uref();
+    storage::write(uref, value);
+}
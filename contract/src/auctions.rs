@@ -0,0 +1,53 @@
+//! Implementation of auctions.
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{account::AccountHash, bytesrepr::ToBytes, ContractHash, Key, URef, U256, U512};
+
+use crate::{constants::AUCTIONS_KEY_NAME, detail, Auction};
+
+#[inline]
+pub(crate) fn auctions_uref() -> URef {
+    detail::get_uref(AUCTIONS_KEY_NAME)
+}
+
+/// Creates a dictionary item key for a (collection, token_id) pair.
+fn make_dictionary_item_key(collection: ContractHash, token_id: U256) -> String {
+    let mut preimage = Vec::new();
+    preimage.append(&mut Key::from(collection).to_bytes().unwrap_or_revert());
+    preimage.append(&mut token_id.to_bytes().unwrap_or_revert());
+
+    let key_bytes = runtime::blake2b(&preimage);
+    hex::encode(&key_bytes)
+}
+
+/// Creates the sealed bid commitment of `bidder` for a bid of `price` hidden with `salt`.
+///
+/// The commitment is the blake2b-256 hash of the bytesrepr encodings concatenated in order:
+/// the 32 byte account hash, the `U512` price (a length byte followed by its little-endian
+/// bytes) and the salt as a `String` (a `u32` little-endian length followed by UTF-8 bytes).
+pub(crate) fn make_commitment(bidder: AccountHash, price: U512, salt: &str) -> [u8; 32] {
+    let mut preimage = Vec::new();
+    preimage.append(&mut bidder.to_bytes().unwrap_or_revert());
+    preimage.append(&mut price.to_bytes().unwrap_or_revert());
+    preimage.append(&mut salt.to_bytes().unwrap_or_revert());
+
+    runtime::blake2b(&preimage)
+}
+
+/// Writes the auction of a token.
+pub(crate) fn write_auction(auction: Auction) {
+    let dictionary_item_key = make_dictionary_item_key(auction.collection, auction.token_id);
+    let auctions_uref = auctions_uref();
+    storage::dictionary_put(auctions_uref, &dictionary_item_key, auction);
+}
+
+/// Reads the last auction of a token, if any.
+pub(crate) fn read_auction(collection: ContractHash, token_id: U256) -> Option<Auction> {
+    let dictionary_item_key = make_dictionary_item_key(collection, token_id);
+    let auctions_uref = auctions_uref();
+    storage::dictionary_get(auctions_uref, &dictionary_item_key).unwrap_or_revert()
+}
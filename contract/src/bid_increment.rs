@@ -0,0 +1,26 @@
+//! Implementation of the minimum bid increment defaults.
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{URef, U512};
+
+use crate::{constants::MIN_BID_INCREMENT_KEY_NAME, detail};
+
+/// Absolute increment and per-mille increment rate, the larger of the two applies.
+pub type MinBidIncrement = (U512, U512);
+
+#[inline]
+pub(crate) fn min_bid_increment_uref() -> URef {
+    detail::get_uref(MIN_BID_INCREMENT_KEY_NAME)
+}
+
+/// Reads the default minimum bid increment.
+pub(crate) fn read_min_bid_increment() -> MinBidIncrement {
+    let uref = min_bid_increment_uref();
+    storage::read(uref).unwrap_or_revert().unwrap_or_default()
+}
+
+/// Writes the default minimum bid increment.
+pub(crate) fn write_min_bid_increment(value: MinBidIncrement) {
+    let uref = min_bid_increment_uref();
+    storage::write(uref, value);
+}
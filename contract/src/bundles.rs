@@ -0,0 +1,40 @@
+//! Implementation of bundles.
+use alloc::string::ToString;
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::URef;
+
+use crate::{
+    constants::{BUNDLES_KEY_NAME, BUNDLE_COUNT_KEY_NAME},
+    detail, Bundle,
+};
+
+#[inline]
+pub(crate) fn bundles_uref() -> URef {
+    detail::get_uref(BUNDLES_KEY_NAME)
+}
+
+#[inline]
+pub(crate) fn bundle_count_uref() -> URef {
+    detail::get_uref(BUNDLE_COUNT_KEY_NAME)
+}
+
+/// Returns a fresh bundle id and bumps the bundle count.
+pub(crate) fn next_bundle_id() -> u64 {
+    let uref = bundle_count_uref();
+    let bundle_id: u64 = storage::read(uref).unwrap_or_revert().unwrap_or_default();
+    storage::write(uref, bundle_id.checked_add(1).unwrap_or_revert());
+    bundle_id
+}
+
+/// Writes a bundle under its id.
+pub(crate) fn write_bundle(bundle: Bundle) {
+    let bundles_uref = bundles_uref();
+    storage::dictionary_put(bundles_uref, &bundle.bundle_id.to_string(), bundle);
+}
+
+/// Reads the bundle with `bundle_id`, if any.
+pub(crate) fn read_bundle(bundle_id: u64) -> Option<Bundle> {
+    let bundles_uref = bundles_uref();
+    storage::dictionary_get(bundles_uref, &bundle_id.to_string()).unwrap_or_revert()
+}
@@ -0,0 +1,45 @@
+//! Implementation of collection offers.
+use alloc::string::ToString;
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::URef;
+
+use crate::{
+    constants::{COLLECTION_OFFERS_KEY_NAME, COLLECTION_OFFER_COUNT_KEY_NAME},
+    detail, CollectionOffer,
+};
+
+#[inline]
+pub(crate) fn collection_offers_uref() -> URef {
+    detail::get_uref(COLLECTION_OFFERS_KEY_NAME)
+}
+
+#[inline]
+pub(crate) fn collection_offer_count_uref() -> URef {
+    detail::get_uref(COLLECTION_OFFER_COUNT_KEY_NAME)
+}
+
+/// Returns a fresh collection offer id and bumps the collection offer count.
+pub(crate) fn next_collection_offer_id() -> u64 {
+    let uref = collection_offer_count_uref();
+    let collection_offer_id: u64 = storage::read(uref).unwrap_or_revert().unwrap_or_default();
+    storage::write(uref, collection_offer_id.checked_add(1).unwrap_or_revert());
+    collection_offer_id
+}
+
+/// Writes a collection offer under its id.
+pub(crate) fn write_collection_offer(collection_offer: CollectionOffer) {
+    let collection_offers_uref = collection_offers_uref();
+    storage::dictionary_put(
+        collection_offers_uref,
+        &collection_offer.collection_offer_id.to_string(),
+        collection_offer,
+    );
+}
+
+/// Reads the collection offer with `collection_offer_id`, if any.
+pub(crate) fn read_collection_offer(collection_offer_id: u64) -> Option<CollectionOffer> {
+    let collection_offers_uref = collection_offers_uref();
+    storage::dictionary_get(collection_offers_uref, &collection_offer_id.to_string())
+        .unwrap_or_revert()
+}
@@ -2,19 +2,28 @@
 pub const CONTRACT_NAME_KEY_NAME: &str = "casper_nft_marketplace";
 pub const TREASURY_WALLET_KEY_NAME: &str = "treasury_wallet";
 pub const ORDERS_KEY_NAME: &str = "orders";
+pub const ORDER_IDS_KEY_NAME: &str = "order_ids";
+pub const ORDER_COUNT_KEY_NAME: &str = "order_count";
 pub const RESULT_KEY_NAME: &str = "result";
 pub const PURSE_KEY_NAME: &str = "purse";
 pub const PURSE_BALANCE_KEY_NAME: &str = "purse_balance";
 pub const FEE_KEY_NAME: &str = "fee";
 pub const ON_ORDERS_KEY_NAME: &str = "on_orders";
 pub const ON_OFFERS_KEY_NAME: &str = "on_offers";
+pub const ON_AUCTIONS_KEY_NAME: &str = "on_auctions";
+pub const AUCTIONS_KEY_NAME: &str = "auctions";
+pub const BUNDLES_KEY_NAME: &str = "bundles";
+pub const BUNDLE_COUNT_KEY_NAME: &str = "bundle_count";
+pub const COLLECTION_OFFERS_KEY_NAME: &str = "collection_offers";
+pub const COLLECTION_OFFER_COUNT_KEY_NAME: &str = "collection_offer_count";
+pub const ANTI_SNIPING_KEY_NAME: &str = "anti_sniping";
+pub const MIN_BID_INCREMENT_KEY_NAME: &str = "min_bid_increment";
 pub const ACCEESS_UREF_KEY_NAME: &str = "casper_nft_marketplace_access";
 
 // RUNTIME ARG NAMES
 pub const COLLECTION_RUNTIME_ARG_NAME: &str = "collection";
 pub const TOKEN_ID_RUNTIME_ARG_NAME: &str = "token_id";
 pub const PRICE_RUNTIME_ARG_NAME: &str = "price";
-pub const BID_ID_RUNTIME_ARG_NAME: &str = "bid_id";
 pub const FEE_RUNTIME_ARG_NAME: &str = "fee";
 pub const AMOUNT_RUNTIME_ARG_NAME: &str = "amount";
 pub const ADMINS_RUNTIME_ARG_NAME: &str = "admins";
@@ -23,20 +32,75 @@ pub const OFFERER_RUNTIME_ARG_NAME: &str = "offerer";
 pub const AUCTION_TYPE_RUNTIME_ARG_NAME: &str = "auction_type";
 pub const START_TIME_RUNTIME_ARG_NAME: &str = "start_time";
 pub const END_TIME_RUNTIME_ARG_NAME: &str = "end_time";
+pub const BIDDER_RUNTIME_ARG_NAME: &str = "bidder";
+pub const FLOOR_PRICE_RUNTIME_ARG_NAME: &str = "floor_price";
+pub const EXTENSION_WINDOW_RUNTIME_ARG_NAME: &str = "extension_window";
+pub const EXTENSION_DURATION_RUNTIME_ARG_NAME: &str = "extension_duration";
+pub const REVEAL_END_TIME_RUNTIME_ARG_NAME: &str = "reveal_end_time";
+pub const SECOND_PRICE_RUNTIME_ARG_NAME: &str = "second_price";
+pub const COMMITMENT_RUNTIME_ARG_NAME: &str = "commitment";
+pub const SALT_RUNTIME_ARG_NAME: &str = "salt";
+pub const MIN_BID_INCREMENT_RUNTIME_ARG_NAME: &str = "min_bid_increment";
+pub const MIN_BID_INCREMENT_RATE_RUNTIME_ARG_NAME: &str = "min_bid_increment_rate";
+pub const SELLER_RUNTIME_ARG_NAME: &str = "seller";
+pub const BUYER_RUNTIME_ARG_NAME: &str = "buyer";
+pub const OFFSET_RUNTIME_ARG_NAME: &str = "offset";
+pub const LIMIT_RUNTIME_ARG_NAME: &str = "limit";
+pub const EXPECTED_PRICE_RUNTIME_ARG_NAME: &str = "expected_price";
+pub const EXPIRES_AT_RUNTIME_ARG_NAME: &str = "expires_at";
+pub const TOKEN_IDS_RUNTIME_ARG_NAME: &str = "token_ids";
+pub const PRICES_RUNTIME_ARG_NAME: &str = "prices";
+pub const COLLECTIONS_RUNTIME_ARG_NAME: &str = "collections";
+pub const BUNDLE_ID_RUNTIME_ARG_NAME: &str = "bundle_id";
+pub const RESERVED_FOR_RUNTIME_ARG_NAME: &str = "reserved_for";
+pub const CUSTODIAL_RUNTIME_ARG_NAME: &str = "custodial";
+pub const ORDER_ID_RUNTIME_ARG_NAME: &str = "order_id";
+pub const REFUND_RUNTIME_ARG_NAME: &str = "refund";
+pub const ORDERS_RUNTIME_ARG_NAME: &str = "orders";
+pub const COLLECTION_OFFER_ID_RUNTIME_ARG_NAME: &str = "collection_offer_id";
+pub const QUANTITY_RUNTIME_ARG_NAME: &str = "quantity";
+pub const TRAITS_RUNTIME_ARG_NAME: &str = "traits";
+pub const MIN_PRICE_RUNTIME_ARG_NAME: &str = "min_price";
 
 // ENTRY POINT NAMES
 pub const SET_TREASURY_WALLET_ENTRY_NAME: &str = "set_treasury_wallet";
 pub const SET_FEE_ENTRY_NAME: &str = "set_fee";
 pub const CREATE_ORDER_ENTRY_NAME: &str = "create_order";
 pub const CANCEL_ORDER_ENTRY_NAME: &str = "cancel_order";
+pub const CREATE_ORDERS_ENTRY_NAME: &str = "create_orders";
+pub const CANCEL_ORDERS_ENTRY_NAME: &str = "cancel_orders";
+pub const CREATE_BUNDLE_ENTRY_NAME: &str = "create_bundle";
+pub const BUY_BUNDLE_ENTRY_NAME: &str = "buy_bundle";
+pub const CANCEL_BUNDLE_ENTRY_NAME: &str = "cancel_bundle";
+pub const CHANGE_ORDER_PRICE_ENTRY_NAME: &str = "change_order_price";
+pub const GET_ORDER_ENTRY_NAME: &str = "get_order";
+pub const REMOVE_EXPIRED_ORDER_ENTRY_NAME: &str = "remove_expired_order";
 pub const BUY_ORDER_ENTRY_NAME: &str = "buy_order";
+pub const BUY_ORDERS_ENTRY_NAME: &str = "buy_orders";
 pub const CREATE_OFFER_ENTRY_NAME: &str = "create_offer";
 pub const CANCEL_OFFER_ENTRY_NAME: &str = "cancel_offer";
 pub const ACCEPT_OFFER_ENTRY_NAME: &str = "accept_offer";
+pub const REJECT_OFFER_ENTRY_NAME: &str = "reject_offer";
+pub const RECLAIM_EXPIRED_OFFERS_ENTRY_NAME: &str = "reclaim_expired_offers";
+pub const CREATE_COLLECTION_OFFER_ENTRY_NAME: &str = "create_collection_offer";
+pub const CANCEL_COLLECTION_OFFER_ENTRY_NAME: &str = "cancel_collection_offer";
+pub const ACCEPT_COLLECTION_OFFER_ENTRY_NAME: &str = "accept_collection_offer";
 pub const CONSTRUCTOR_ENTRY_NAME: &str = "constructor";
 pub const GET_PURSE_ENTRY_NAME: &str = "get_purse";
 pub const GET_ACCESS_UREF_ENTRY_NAME: &str = "get_access_uref";
 pub const CREATE_AUCTION_ENTRY_NAME: &str = "create_auction";
+pub const PLACE_BID_ENTRY_NAME: &str = "place_bid";
+pub const SETTLE_AUCTION_ENTRY_NAME: &str = "settle_auction";
+pub const GET_AUCTION_PRICE_ENTRY_NAME: &str = "get_auction_price";
+pub const SET_ANTI_SNIPING_ENTRY_NAME: &str = "set_anti_sniping";
+pub const SET_MIN_BID_INCREMENT_ENTRY_NAME: &str = "set_min_bid_increment";
+pub const COMMIT_BID_ENTRY_NAME: &str = "commit_bid";
+pub const REVEAL_BID_ENTRY_NAME: &str = "reveal_bid";
+pub const CANCEL_AUCTION_ENTRY_NAME: &str = "cancel_auction";
+pub const FORCE_CANCEL_AUCTION_ENTRY_NAME: &str = "force_cancel_auction";
+pub const GET_AUCTIONS_BY_COLLECTION_ENTRY_NAME: &str = "get_auctions_by_collection";
+pub const GET_AUCTIONS_BY_SELLER_ENTRY_NAME: &str = "get_auctions_by_seller";
+pub const GET_AUCTIONS_BY_END_TIME_ENTRY_NAME: &str = "get_auctions_by_end_time";
 
 // GROUP NAMES
 pub const ADMINS_GROUP_NAME: &str = "admins";
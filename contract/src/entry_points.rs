@@ -1,16 +1,35 @@
-use alloc::{boxed::Box, string::String, vec};
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
 use casper_types::{
-    CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, Group, Parameter,
+    account::AccountHash, CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType,
+    EntryPoints, Group, Key, Parameter, U256, U512,
 };
 
 use crate::constants::{
-    ACCEPT_OFFER_ENTRY_NAME, ADMINS_GROUP_NAME, AMOUNT_RUNTIME_ARG_NAME,
-    AUCTION_TYPE_RUNTIME_ARG_NAME, BID_ID_RUNTIME_ARG_NAME, BUY_ORDER_ENTRY_NAME,
-    CANCEL_OFFER_ENTRY_NAME, CANCEL_ORDER_ENTRY_NAME, COLLECTION_RUNTIME_ARG_NAME,
-    CONSTRUCTOR_ENTRY_NAME, CREATE_AUCTION_ENTRY_NAME, CREATE_OFFER_ENTRY_NAME,
-    CREATE_ORDER_ENTRY_NAME, END_TIME_RUNTIME_ARG_NAME, FEE_RUNTIME_ARG_NAME,
-    GET_ACCESS_UREF_ENTRY_NAME, GET_PURSE_ENTRY_NAME, PRICE_RUNTIME_ARG_NAME, SET_FEE_ENTRY_NAME,
-    SET_TREASURY_WALLET_ENTRY_NAME, START_TIME_RUNTIME_ARG_NAME, TOKEN_ID_RUNTIME_ARG_NAME,
+    ACCEPT_COLLECTION_OFFER_ENTRY_NAME, ACCEPT_OFFER_ENTRY_NAME, ADMINS_GROUP_NAME,
+    AMOUNT_RUNTIME_ARG_NAME, AUCTION_TYPE_RUNTIME_ARG_NAME, BIDDER_RUNTIME_ARG_NAME,
+    BUNDLE_ID_RUNTIME_ARG_NAME, BUY_BUNDLE_ENTRY_NAME, BUY_ORDERS_ENTRY_NAME, BUY_ORDER_ENTRY_NAME,
+    CANCEL_AUCTION_ENTRY_NAME, CANCEL_BUNDLE_ENTRY_NAME, CANCEL_COLLECTION_OFFER_ENTRY_NAME,
+    CANCEL_OFFER_ENTRY_NAME, CANCEL_ORDERS_ENTRY_NAME, CANCEL_ORDER_ENTRY_NAME,
+    CHANGE_ORDER_PRICE_ENTRY_NAME, COLLECTIONS_RUNTIME_ARG_NAME,
+    COLLECTION_OFFER_ID_RUNTIME_ARG_NAME, COLLECTION_RUNTIME_ARG_NAME, COMMITMENT_RUNTIME_ARG_NAME,
+    COMMIT_BID_ENTRY_NAME, CONSTRUCTOR_ENTRY_NAME, CREATE_AUCTION_ENTRY_NAME,
+    CREATE_BUNDLE_ENTRY_NAME, CREATE_COLLECTION_OFFER_ENTRY_NAME, CREATE_OFFER_ENTRY_NAME,
+    CREATE_ORDERS_ENTRY_NAME, CREATE_ORDER_ENTRY_NAME, CUSTODIAL_RUNTIME_ARG_NAME,
+    END_TIME_RUNTIME_ARG_NAME, EXPECTED_PRICE_RUNTIME_ARG_NAME, EXPIRES_AT_RUNTIME_ARG_NAME,
+    EXTENSION_DURATION_RUNTIME_ARG_NAME, EXTENSION_WINDOW_RUNTIME_ARG_NAME, FEE_RUNTIME_ARG_NAME,
+    FLOOR_PRICE_RUNTIME_ARG_NAME, FORCE_CANCEL_AUCTION_ENTRY_NAME, GET_ACCESS_UREF_ENTRY_NAME,
+    GET_AUCTIONS_BY_COLLECTION_ENTRY_NAME, GET_AUCTIONS_BY_END_TIME_ENTRY_NAME,
+    GET_AUCTIONS_BY_SELLER_ENTRY_NAME, GET_AUCTION_PRICE_ENTRY_NAME, GET_ORDER_ENTRY_NAME,
+    GET_PURSE_ENTRY_NAME, LIMIT_RUNTIME_ARG_NAME, MIN_BID_INCREMENT_RATE_RUNTIME_ARG_NAME,
+    MIN_BID_INCREMENT_RUNTIME_ARG_NAME, MIN_PRICE_RUNTIME_ARG_NAME, OFFSET_RUNTIME_ARG_NAME,
+    ORDERS_RUNTIME_ARG_NAME, ORDER_ID_RUNTIME_ARG_NAME, PLACE_BID_ENTRY_NAME,
+    PRICES_RUNTIME_ARG_NAME, PRICE_RUNTIME_ARG_NAME, QUANTITY_RUNTIME_ARG_NAME,
+    RECLAIM_EXPIRED_OFFERS_ENTRY_NAME, REJECT_OFFER_ENTRY_NAME, REMOVE_EXPIRED_ORDER_ENTRY_NAME,
+    RESERVED_FOR_RUNTIME_ARG_NAME, REVEAL_BID_ENTRY_NAME, REVEAL_END_TIME_RUNTIME_ARG_NAME,
+    SALT_RUNTIME_ARG_NAME, SECOND_PRICE_RUNTIME_ARG_NAME, SELLER_RUNTIME_ARG_NAME,
+    SETTLE_AUCTION_ENTRY_NAME, SET_ANTI_SNIPING_ENTRY_NAME, SET_FEE_ENTRY_NAME,
+    SET_MIN_BID_INCREMENT_ENTRY_NAME, SET_TREASURY_WALLET_ENTRY_NAME, START_TIME_RUNTIME_ARG_NAME,
+    TOKEN_IDS_RUNTIME_ARG_NAME, TOKEN_ID_RUNTIME_ARG_NAME, TRAITS_RUNTIME_ARG_NAME,
     TREASURY_WALLET_RUNTIME_ARG_NAME,
 };
 
@@ -50,6 +69,34 @@ pub fn set_fee() -> EntryPoint {
     )
 }
 
+/// Returns the `set_anti_sniping` entry point.
+pub fn set_anti_sniping() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_ANTI_SNIPING_ENTRY_NAME),
+        vec![
+            Parameter::new(EXTENSION_WINDOW_RUNTIME_ARG_NAME, CLType::U64),
+            Parameter::new(EXTENSION_DURATION_RUNTIME_ARG_NAME, CLType::U64),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![Group::new(ADMINS_GROUP_NAME)]),
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `set_min_bid_increment` entry point.
+pub fn set_min_bid_increment() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SET_MIN_BID_INCREMENT_ENTRY_NAME),
+        vec![
+            Parameter::new(MIN_BID_INCREMENT_RUNTIME_ARG_NAME, CLType::U512),
+            Parameter::new(MIN_BID_INCREMENT_RATE_RUNTIME_ARG_NAME, CLType::U512),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![Group::new(ADMINS_GROUP_NAME)]),
+        EntryPointType::Contract,
+    )
+}
+
 pub fn get_access_uref() -> EntryPoint {
     EntryPoint::new(
         String::from(GET_ACCESS_UREF_ENTRY_NAME),
@@ -68,6 +115,15 @@ pub fn create_order() -> EntryPoint {
             Parameter::new(COLLECTION_RUNTIME_ARG_NAME, CLType::Key),
             Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, CLType::U256),
             Parameter::new(PRICE_RUNTIME_ARG_NAME, CLType::U256),
+            Parameter::new(
+                EXPIRES_AT_RUNTIME_ARG_NAME,
+                CLType::Option(Box::new(CLType::U64)),
+            ),
+            Parameter::new(
+                RESERVED_FOR_RUNTIME_ARG_NAME,
+                CLType::Option(Box::new(AccountHash::cl_type())),
+            ),
+            Parameter::new(CUSTODIAL_RUNTIME_ARG_NAME, CLType::Bool),
         ],
         CLType::U256,
         EntryPointAccess::Public,
@@ -75,6 +131,48 @@ pub fn create_order() -> EntryPoint {
     )
 }
 
+/// Returns the `create_orders` entry point.
+pub fn create_orders() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CREATE_ORDERS_ENTRY_NAME),
+        vec![
+            Parameter::new(COLLECTION_RUNTIME_ARG_NAME, CLType::Key),
+            Parameter::new(
+                TOKEN_IDS_RUNTIME_ARG_NAME,
+                CLType::List(Box::new(CLType::U256)),
+            ),
+            Parameter::new(
+                PRICES_RUNTIME_ARG_NAME,
+                CLType::List(Box::new(CLType::U512)),
+            ),
+            Parameter::new(
+                EXPIRES_AT_RUNTIME_ARG_NAME,
+                CLType::Option(Box::new(CLType::U64)),
+            ),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `cancel_orders` entry point.
+pub fn cancel_orders() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CANCEL_ORDERS_ENTRY_NAME),
+        vec![
+            Parameter::new(COLLECTION_RUNTIME_ARG_NAME, CLType::Key),
+            Parameter::new(
+                TOKEN_IDS_RUNTIME_ARG_NAME,
+                CLType::List(Box::new(CLType::U256)),
+            ),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `cancel_order` entry point.
 pub fn cancel_order() -> EntryPoint {
     EntryPoint::new(
@@ -89,6 +187,60 @@ pub fn cancel_order() -> EntryPoint {
     )
 }
 
+/// Returns the `change_order_price` entry point.
+pub fn change_order_price() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CHANGE_ORDER_PRICE_ENTRY_NAME),
+        vec![
+            Parameter::new(COLLECTION_RUNTIME_ARG_NAME, CLType::Key),
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, CLType::U256),
+            Parameter::new(PRICE_RUNTIME_ARG_NAME, CLType::U512),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `remove_expired_order` entry point.
+pub fn remove_expired_order() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REMOVE_EXPIRED_ORDER_ENTRY_NAME),
+        vec![
+            Parameter::new(COLLECTION_RUNTIME_ARG_NAME, CLType::Key),
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, CLType::U256),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `buy_orders` entry point.
+pub fn buy_orders() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BUY_ORDERS_ENTRY_NAME),
+        vec![
+            Parameter::new(ORDERS_RUNTIME_ARG_NAME, Vec::<(Key, U256, U512)>::cl_type()),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, CLType::U512),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_order` entry point.
+pub fn get_order() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_ORDER_ENTRY_NAME),
+        vec![Parameter::new(ORDER_ID_RUNTIME_ARG_NAME, CLType::U64)],
+        CLType::Any,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `buy_order` entry point.
 pub fn buy_order() -> EntryPoint {
     EntryPoint::new(
@@ -96,6 +248,43 @@ pub fn buy_order() -> EntryPoint {
         vec![
             Parameter::new(COLLECTION_RUNTIME_ARG_NAME, CLType::Key),
             Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, CLType::U256),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, CLType::U512),
+            Parameter::new(EXPECTED_PRICE_RUNTIME_ARG_NAME, CLType::U512),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `create_bundle` entry point.
+pub fn create_bundle() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CREATE_BUNDLE_ENTRY_NAME),
+        vec![
+            Parameter::new(
+                COLLECTIONS_RUNTIME_ARG_NAME,
+                CLType::List(Box::new(CLType::Key)),
+            ),
+            Parameter::new(
+                TOKEN_IDS_RUNTIME_ARG_NAME,
+                CLType::List(Box::new(CLType::U256)),
+            ),
+            Parameter::new(PRICE_RUNTIME_ARG_NAME, CLType::U512),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `buy_bundle` entry point.
+pub fn buy_bundle() -> EntryPoint {
+    EntryPoint::new(
+        String::from(BUY_BUNDLE_ENTRY_NAME),
+        vec![
+            Parameter::new(BUNDLE_ID_RUNTIME_ARG_NAME, CLType::U64),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, CLType::U512),
         ],
         CLType::Unit,
         EntryPointAccess::Public,
@@ -103,6 +292,17 @@ pub fn buy_order() -> EntryPoint {
     )
 }
 
+/// Returns the `cancel_bundle` entry point.
+pub fn cancel_bundle() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CANCEL_BUNDLE_ENTRY_NAME),
+        vec![Parameter::new(BUNDLE_ID_RUNTIME_ARG_NAME, CLType::U64)],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 /// Returns the `create_offer` entry point.
 pub fn create_offer() -> EntryPoint {
     EntryPoint::new(
@@ -111,6 +311,90 @@ pub fn create_offer() -> EntryPoint {
             Parameter::new(COLLECTION_RUNTIME_ARG_NAME, CLType::Key),
             Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, CLType::U256),
             Parameter::new(AMOUNT_RUNTIME_ARG_NAME, CLType::U512),
+            Parameter::new(
+                EXPIRES_AT_RUNTIME_ARG_NAME,
+                CLType::Option(Box::new(CLType::U64)),
+            ),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `reject_offer` entry point.
+pub fn reject_offer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REJECT_OFFER_ENTRY_NAME),
+        vec![
+            Parameter::new(COLLECTION_RUNTIME_ARG_NAME, CLType::Key),
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, CLType::U256),
+            Parameter::new(BIDDER_RUNTIME_ARG_NAME, AccountHash::cl_type()),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `reclaim_expired_offers` entry point.
+pub fn reclaim_expired_offers() -> EntryPoint {
+    EntryPoint::new(
+        String::from(RECLAIM_EXPIRED_OFFERS_ENTRY_NAME),
+        vec![
+            Parameter::new(COLLECTION_RUNTIME_ARG_NAME, CLType::Key),
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, CLType::U256),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `create_collection_offer` entry point.
+pub fn create_collection_offer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CREATE_COLLECTION_OFFER_ENTRY_NAME),
+        vec![
+            Parameter::new(COLLECTION_RUNTIME_ARG_NAME, CLType::Key),
+            Parameter::new(PRICE_RUNTIME_ARG_NAME, CLType::U512),
+            Parameter::new(QUANTITY_RUNTIME_ARG_NAME, CLType::U64),
+            Parameter::new(
+                TRAITS_RUNTIME_ARG_NAME,
+                CLType::Map {
+                    key: Box::new(CLType::String),
+                    value: Box::new(CLType::String),
+                },
+            ),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, CLType::U512),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `cancel_collection_offer` entry point.
+pub fn cancel_collection_offer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CANCEL_COLLECTION_OFFER_ENTRY_NAME),
+        vec![Parameter::new(
+            COLLECTION_OFFER_ID_RUNTIME_ARG_NAME,
+            CLType::U64,
+        )],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `accept_collection_offer` entry point.
+pub fn accept_collection_offer() -> EntryPoint {
+    EntryPoint::new(
+        String::from(ACCEPT_COLLECTION_OFFER_ENTRY_NAME),
+        vec![
+            Parameter::new(COLLECTION_OFFER_ID_RUNTIME_ARG_NAME, CLType::U64),
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, CLType::U256),
         ],
         CLType::Unit,
         EntryPointAccess::Public,
@@ -139,7 +423,8 @@ pub fn accept_offer() -> EntryPoint {
         vec![
             Parameter::new(COLLECTION_RUNTIME_ARG_NAME, CLType::Key),
             Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, CLType::U256),
-            Parameter::new(BID_ID_RUNTIME_ARG_NAME, CLType::U8),
+            Parameter::new(BIDDER_RUNTIME_ARG_NAME, AccountHash::cl_type()),
+            Parameter::new(MIN_PRICE_RUNTIME_ARG_NAME, CLType::U512),
         ],
         CLType::Unit,
         EntryPointAccess::Public,
@@ -147,6 +432,7 @@ pub fn accept_offer() -> EntryPoint {
     )
 }
 
+/// Returns the `create_auction` entry point.
 pub fn create_auction() -> EntryPoint {
     EntryPoint::new(
         String::from(CREATE_AUCTION_ENTRY_NAME),
@@ -158,10 +444,185 @@ pub fn create_auction() -> EntryPoint {
                 PRICE_RUNTIME_ARG_NAME,
                 CLType::Option(Box::new(CLType::U512)),
             ),
-            Parameter::new(START_TIME_RUNTIME_ARG_NAME, CLType::U256),
-            Parameter::new(END_TIME_RUNTIME_ARG_NAME, CLType::U256),
+            Parameter::new(
+                FLOOR_PRICE_RUNTIME_ARG_NAME,
+                CLType::Option(Box::new(CLType::U512)),
+            ),
+            Parameter::new(START_TIME_RUNTIME_ARG_NAME, CLType::U64),
+            Parameter::new(
+                END_TIME_RUNTIME_ARG_NAME,
+                CLType::Option(Box::new(CLType::U64)),
+            ),
+            Parameter::new(
+                REVEAL_END_TIME_RUNTIME_ARG_NAME,
+                CLType::Option(Box::new(CLType::U64)),
+            ),
+            Parameter::new(SECOND_PRICE_RUNTIME_ARG_NAME, CLType::Bool),
+            Parameter::new(
+                EXTENSION_WINDOW_RUNTIME_ARG_NAME,
+                CLType::Option(Box::new(CLType::U64)),
+            ),
+            Parameter::new(
+                EXTENSION_DURATION_RUNTIME_ARG_NAME,
+                CLType::Option(Box::new(CLType::U64)),
+            ),
+            Parameter::new(
+                MIN_BID_INCREMENT_RUNTIME_ARG_NAME,
+                CLType::Option(Box::new(CLType::U512)),
+            ),
+            Parameter::new(
+                MIN_BID_INCREMENT_RATE_RUNTIME_ARG_NAME,
+                CLType::Option(Box::new(CLType::U512)),
+            ),
         ],
-        CLType::U256,
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `place_bid` entry point.
+pub fn place_bid() -> EntryPoint {
+    EntryPoint::new(
+        String::from(PLACE_BID_ENTRY_NAME),
+        vec![
+            Parameter::new(COLLECTION_RUNTIME_ARG_NAME, CLType::Key),
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, CLType::U256),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, CLType::U512),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `commit_bid` entry point.
+pub fn commit_bid() -> EntryPoint {
+    EntryPoint::new(
+        String::from(COMMIT_BID_ENTRY_NAME),
+        vec![
+            Parameter::new(COLLECTION_RUNTIME_ARG_NAME, CLType::Key),
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, CLType::U256),
+            Parameter::new(COMMITMENT_RUNTIME_ARG_NAME, CLType::ByteArray(32)),
+            Parameter::new(AMOUNT_RUNTIME_ARG_NAME, CLType::U512),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `reveal_bid` entry point.
+pub fn reveal_bid() -> EntryPoint {
+    EntryPoint::new(
+        String::from(REVEAL_BID_ENTRY_NAME),
+        vec![
+            Parameter::new(COLLECTION_RUNTIME_ARG_NAME, CLType::Key),
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, CLType::U256),
+            Parameter::new(PRICE_RUNTIME_ARG_NAME, CLType::U512),
+            Parameter::new(SALT_RUNTIME_ARG_NAME, CLType::String),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `settle_auction` entry point.
+pub fn settle_auction() -> EntryPoint {
+    EntryPoint::new(
+        String::from(SETTLE_AUCTION_ENTRY_NAME),
+        vec![
+            Parameter::new(COLLECTION_RUNTIME_ARG_NAME, CLType::Key),
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, CLType::U256),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `cancel_auction` entry point.
+pub fn cancel_auction() -> EntryPoint {
+    EntryPoint::new(
+        String::from(CANCEL_AUCTION_ENTRY_NAME),
+        vec![
+            Parameter::new(COLLECTION_RUNTIME_ARG_NAME, CLType::Key),
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, CLType::U256),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `force_cancel_auction` entry point.
+pub fn force_cancel_auction() -> EntryPoint {
+    EntryPoint::new(
+        String::from(FORCE_CANCEL_AUCTION_ENTRY_NAME),
+        vec![
+            Parameter::new(COLLECTION_RUNTIME_ARG_NAME, CLType::Key),
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, CLType::U256),
+        ],
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![Group::new(ADMINS_GROUP_NAME)]),
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_auction_price` entry point.
+pub fn get_auction_price() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_AUCTION_PRICE_ENTRY_NAME),
+        vec![
+            Parameter::new(COLLECTION_RUNTIME_ARG_NAME, CLType::Key),
+            Parameter::new(TOKEN_ID_RUNTIME_ARG_NAME, CLType::U256),
+        ],
+        CLType::U512,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_auctions_by_collection` entry point.
+pub fn get_auctions_by_collection() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_AUCTIONS_BY_COLLECTION_ENTRY_NAME),
+        vec![
+            Parameter::new(COLLECTION_RUNTIME_ARG_NAME, CLType::Key),
+            Parameter::new(OFFSET_RUNTIME_ARG_NAME, CLType::U64),
+            Parameter::new(LIMIT_RUNTIME_ARG_NAME, CLType::U64),
+        ],
+        CLType::List(Box::new(CLType::Any)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_auctions_by_seller` entry point.
+pub fn get_auctions_by_seller() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_AUCTIONS_BY_SELLER_ENTRY_NAME),
+        vec![
+            Parameter::new(SELLER_RUNTIME_ARG_NAME, CLType::Key),
+            Parameter::new(OFFSET_RUNTIME_ARG_NAME, CLType::U64),
+            Parameter::new(LIMIT_RUNTIME_ARG_NAME, CLType::U64),
+        ],
+        CLType::List(Box::new(CLType::Any)),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+/// Returns the `get_auctions_by_end_time` entry point.
+pub fn get_auctions_by_end_time() -> EntryPoint {
+    EntryPoint::new(
+        String::from(GET_AUCTIONS_BY_END_TIME_ENTRY_NAME),
+        vec![
+            Parameter::new(OFFSET_RUNTIME_ARG_NAME, CLType::U64),
+            Parameter::new(LIMIT_RUNTIME_ARG_NAME, CLType::U64),
+        ],
+        CLType::List(Box::new(CLType::Any)),
         EntryPointAccess::Public,
         EntryPointType::Contract,
     )
@@ -183,14 +644,40 @@ pub fn default() -> EntryPoints {
     entry_points.add_entry_point(constructor());
     entry_points.add_entry_point(set_treasury_wallet());
     entry_points.add_entry_point(set_fee());
+    entry_points.add_entry_point(set_anti_sniping());
+    entry_points.add_entry_point(set_min_bid_increment());
     entry_points.add_entry_point(get_purse());
     entry_points.add_entry_point(create_order());
     entry_points.add_entry_point(cancel_order());
+    entry_points.add_entry_point(create_orders());
+    entry_points.add_entry_point(cancel_orders());
+    entry_points.add_entry_point(change_order_price());
+    entry_points.add_entry_point(remove_expired_order());
     entry_points.add_entry_point(create_offer());
     entry_points.add_entry_point(cancel_offer());
     entry_points.add_entry_point(accept_offer());
+    entry_points.add_entry_point(reject_offer());
+    entry_points.add_entry_point(reclaim_expired_offers());
+    entry_points.add_entry_point(create_collection_offer());
+    entry_points.add_entry_point(cancel_collection_offer());
+    entry_points.add_entry_point(accept_collection_offer());
     entry_points.add_entry_point(create_auction());
+    entry_points.add_entry_point(place_bid());
+    entry_points.add_entry_point(commit_bid());
+    entry_points.add_entry_point(reveal_bid());
+    entry_points.add_entry_point(settle_auction());
+    entry_points.add_entry_point(cancel_auction());
+    entry_points.add_entry_point(force_cancel_auction());
+    entry_points.add_entry_point(get_auction_price());
+    entry_points.add_entry_point(get_auctions_by_collection());
+    entry_points.add_entry_point(get_auctions_by_seller());
+    entry_points.add_entry_point(get_auctions_by_end_time());
     entry_points.add_entry_point(buy_order());
+    entry_points.add_entry_point(buy_orders());
+    entry_points.add_entry_point(get_order());
+    entry_points.add_entry_point(create_bundle());
+    entry_points.add_entry_point(buy_bundle());
+    entry_points.add_entry_point(cancel_bundle());
     entry_points.add_entry_point(get_access_uref());
     entry_points
 }
@@ -28,6 +28,26 @@ pub enum Error {
     OrderNotExist = 48,
     OfferExist = 49,
     OfferNotExist = 50,
+    AuctionExist = 51,
+    AuctionNotExist = 52,
+    InvalidAuctionTime = 53,
+    AuctionNotStarted = 54,
+    AuctionEnded = 55,
+    AuctionNotEnded = 56,
+    BidTooLow = 57,
+    InvalidAuctionPrice = 58,
+    InvalidAuctionType = 59,
+    InvalidReveal = 60,
+    BidIncrementTooLow = 61,
+    UnexpectedPrice = 62,
+    OrderExpired = 63,
+    OrderNotExpired = 64,
+    InvalidBatch = 65,
+    BundleNotExist = 66,
+    NotReservedBuyer = 67,
+    OfferExpired = 68,
+    CollectionOfferNotExist = 69,
+    TraitMismatch = 70,
     // Contract Error
     InvalidContext = 90,
     KeyAlreadyExists = 91,
@@ -53,7 +73,27 @@ impl From<Error> for ApiError {
             | Error::OrderExist
             | Error::OrderNotExist
             | Error::OfferExist
-            | Error::OfferNotExist => ApiError::User(error as u16),
+            | Error::OfferNotExist
+            | Error::AuctionExist
+            | Error::AuctionNotExist
+            | Error::InvalidAuctionTime
+            | Error::AuctionNotStarted
+            | Error::AuctionEnded
+            | Error::AuctionNotEnded
+            | Error::BidTooLow
+            | Error::InvalidAuctionPrice
+            | Error::InvalidAuctionType
+            | Error::InvalidReveal
+            | Error::BidIncrementTooLow
+            | Error::UnexpectedPrice
+            | Error::OrderExpired
+            | Error::OrderNotExpired
+            | Error::InvalidBatch
+            | Error::BundleNotExist
+            | Error::NotReservedBuyer
+            | Error::OfferExpired
+            | Error::CollectionOfferNotExist
+            | Error::TraitMismatch => ApiError::User(error as u16),
         }
     }
 }
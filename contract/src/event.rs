@@ -2,32 +2,87 @@ use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
 use casper_contract::contract_api::storage;
 use casper_types::{account::AccountHash, Key, URef, U256, U512};
 
-use crate::constants::{
-    ACCEPT_OFFER_ENTRY_NAME, BUY_ORDER_ENTRY_NAME, CANCEL_OFFER_ENTRY_NAME,
-    CANCEL_ORDER_ENTRY_NAME, COLLECTION_RUNTIME_ARG_NAME, CREATE_OFFER_ENTRY_NAME,
-    CREATE_ORDER_ENTRY_NAME, FEE_RUNTIME_ARG_NAME, OFFERER_RUNTIME_ARG_NAME,
-    PRICE_RUNTIME_ARG_NAME, SET_FEE_ENTRY_NAME, SET_TREASURY_WALLET_ENTRY_NAME,
-    TOKEN_ID_RUNTIME_ARG_NAME, TREASURY_WALLET_RUNTIME_ARG_NAME,
+use crate::{
+    constants::{
+        ACCEPT_COLLECTION_OFFER_ENTRY_NAME, ACCEPT_OFFER_ENTRY_NAME, AUCTION_TYPE_RUNTIME_ARG_NAME,
+        BIDDER_RUNTIME_ARG_NAME, BUNDLE_ID_RUNTIME_ARG_NAME, BUYER_RUNTIME_ARG_NAME,
+        BUY_BUNDLE_ENTRY_NAME, BUY_ORDER_ENTRY_NAME, CANCEL_AUCTION_ENTRY_NAME,
+        CANCEL_BUNDLE_ENTRY_NAME, CANCEL_COLLECTION_OFFER_ENTRY_NAME, CANCEL_OFFER_ENTRY_NAME,
+        CANCEL_ORDER_ENTRY_NAME, CHANGE_ORDER_PRICE_ENTRY_NAME,
+        COLLECTION_OFFER_ID_RUNTIME_ARG_NAME, COLLECTION_RUNTIME_ARG_NAME, COMMIT_BID_ENTRY_NAME,
+        CREATE_AUCTION_ENTRY_NAME, CREATE_BUNDLE_ENTRY_NAME, CREATE_COLLECTION_OFFER_ENTRY_NAME,
+        CREATE_OFFER_ENTRY_NAME, CREATE_ORDER_ENTRY_NAME, END_TIME_RUNTIME_ARG_NAME,
+        EXTENSION_DURATION_RUNTIME_ARG_NAME, EXTENSION_WINDOW_RUNTIME_ARG_NAME,
+        FEE_RUNTIME_ARG_NAME, MIN_BID_INCREMENT_RATE_RUNTIME_ARG_NAME,
+        MIN_BID_INCREMENT_RUNTIME_ARG_NAME, OFFERER_RUNTIME_ARG_NAME, ORDER_ID_RUNTIME_ARG_NAME,
+        PLACE_BID_ENTRY_NAME, PRICE_RUNTIME_ARG_NAME, QUANTITY_RUNTIME_ARG_NAME,
+        RECLAIM_EXPIRED_OFFERS_ENTRY_NAME, REFUND_RUNTIME_ARG_NAME, REJECT_OFFER_ENTRY_NAME,
+        REMOVE_EXPIRED_ORDER_ENTRY_NAME, RESERVED_FOR_RUNTIME_ARG_NAME, REVEAL_BID_ENTRY_NAME,
+        SELLER_RUNTIME_ARG_NAME, SETTLE_AUCTION_ENTRY_NAME, SET_ANTI_SNIPING_ENTRY_NAME,
+        SET_FEE_ENTRY_NAME, SET_MIN_BID_INCREMENT_ENTRY_NAME, SET_TREASURY_WALLET_ENTRY_NAME,
+        START_TIME_RUNTIME_ARG_NAME, TOKEN_ID_RUNTIME_ARG_NAME, TREASURY_WALLET_RUNTIME_ARG_NAME,
+    },
+    AuctionStatus, AuctionType,
 };
 
 pub enum Event {
     OrderCreated {
+        order_id: u64,
         offerer: AccountHash,
         collection: Key,
         token_id: U256,
         price: U512,
+        reserved_for: Option<AccountHash>,
     },
     OrderBought {
-        offerer: AccountHash,
+        order_id: u64,
+        seller: AccountHash,
+        buyer: AccountHash,
         collection: Key,
         token_id: U256,
         price: U512,
+        refund: U512,
     },
     OrderCanceled {
+        order_id: u64,
         offerer: AccountHash,
         collection: Key,
         token_id: U256,
     },
+    OrderExpired {
+        order_id: u64,
+        offerer: AccountHash,
+        collection: Key,
+        token_id: U256,
+    },
+    OrderInvalidated {
+        order_id: u64,
+        offerer: AccountHash,
+        collection: Key,
+        token_id: U256,
+    },
+    OrderPriceChanged {
+        order_id: u64,
+        offerer: AccountHash,
+        collection: Key,
+        token_id: U256,
+        price: U512,
+    },
+    BundleCreated {
+        offerer: AccountHash,
+        bundle_id: u64,
+        price: U512,
+    },
+    BundleBought {
+        seller: AccountHash,
+        buyer: AccountHash,
+        bundle_id: u64,
+        price: U512,
+    },
+    BundleCanceled {
+        offerer: AccountHash,
+        bundle_id: u64,
+    },
     OfferCreated {
         offerer: AccountHash,
         collection: Key,
@@ -36,6 +91,7 @@ pub enum Event {
     },
     OfferAccepted {
         offerer: AccountHash,
+        bidder: AccountHash,
         collection: Key,
         token_id: U256,
         price: U512,
@@ -45,6 +101,90 @@ pub enum Event {
         collection: Key,
         token_id: U256,
     },
+    OfferRejected {
+        offerer: AccountHash,
+        bidder: AccountHash,
+        collection: Key,
+        token_id: U256,
+        price: U512,
+    },
+    OfferExpired {
+        offerer: AccountHash,
+        collection: Key,
+        token_id: U256,
+        price: U512,
+    },
+    CollectionOfferCreated {
+        offerer: AccountHash,
+        collection_offer_id: u64,
+        collection: Key,
+        price: U512,
+        quantity: u64,
+    },
+    CollectionOfferAccepted {
+        seller: AccountHash,
+        buyer: AccountHash,
+        collection_offer_id: u64,
+        collection: Key,
+        token_id: U256,
+        price: U512,
+    },
+    CollectionOfferCanceled {
+        offerer: AccountHash,
+        collection_offer_id: u64,
+        refund: U512,
+    },
+    AuctionCreated {
+        offerer: AccountHash,
+        collection: Key,
+        token_id: U256,
+        auction_type: AuctionType,
+        start_time: u64,
+        end_time: Option<u64>,
+    },
+    BidPlaced {
+        bidder: AccountHash,
+        collection: Key,
+        token_id: U256,
+        price: U512,
+    },
+    BidCommitted {
+        bidder: AccountHash,
+        collection: Key,
+        token_id: U256,
+    },
+    BidRevealed {
+        bidder: AccountHash,
+        collection: Key,
+        token_id: U256,
+        price: U512,
+    },
+    AuctionSettled {
+        offerer: AccountHash,
+        bidder: Option<AccountHash>,
+        collection: Key,
+        token_id: U256,
+        price: U512,
+        status: AuctionStatus,
+    },
+    AuctionCanceled {
+        offerer: AccountHash,
+        collection: Key,
+        token_id: U256,
+    },
+    AuctionExtended {
+        collection: Key,
+        token_id: U256,
+        end_time: u64,
+    },
+    AntiSnipingChanged {
+        extension_window: u64,
+        extension_duration: u64,
+    },
+    MinBidIncrementChanged {
+        min_bid_increment: U512,
+        min_bid_increment_rate: U512,
+    },
     TreasuryWalletChanged {
         treasury_wallet: AccountHash,
     },
@@ -57,46 +197,137 @@ pub(crate) fn emit(event: &Event) {
     let mut events = Vec::new();
     match event {
         Event::OrderCreated {
+            order_id,
             offerer,
             collection,
             token_id,
             price,
+            reserved_for,
         } => {
             let mut param = BTreeMap::new();
             param.insert("event_type", CREATE_ORDER_ENTRY_NAME.to_string());
+            param.insert(ORDER_ID_RUNTIME_ARG_NAME, order_id.to_string());
             param.insert(OFFERER_RUNTIME_ARG_NAME, offerer.to_string());
             param.insert(COLLECTION_RUNTIME_ARG_NAME, collection.to_string());
             param.insert(TOKEN_ID_RUNTIME_ARG_NAME, token_id.to_string());
             param.insert(PRICE_RUNTIME_ARG_NAME, price.to_string());
+            if let Some(reserved_for) = reserved_for {
+                param.insert(RESERVED_FOR_RUNTIME_ARG_NAME, reserved_for.to_string());
+            }
             events.push(param);
         }
         Event::OrderBought {
-            offerer,
+            order_id,
+            seller,
+            buyer,
             collection,
             token_id,
             price,
+            refund,
         } => {
             let mut param = BTreeMap::new();
             param.insert("event_type", BUY_ORDER_ENTRY_NAME.to_string());
-            param.insert(OFFERER_RUNTIME_ARG_NAME, offerer.to_string());
+            param.insert(ORDER_ID_RUNTIME_ARG_NAME, order_id.to_string());
+            param.insert(SELLER_RUNTIME_ARG_NAME, seller.to_string());
+            param.insert(BUYER_RUNTIME_ARG_NAME, buyer.to_string());
             param.insert(COLLECTION_RUNTIME_ARG_NAME, collection.to_string());
             param.insert(TOKEN_ID_RUNTIME_ARG_NAME, token_id.to_string());
             param.insert(PRICE_RUNTIME_ARG_NAME, price.to_string());
+            param.insert(REFUND_RUNTIME_ARG_NAME, refund.to_string());
             events.push(param);
         }
         Event::OrderCanceled {
+            order_id,
             offerer,
             collection,
             token_id,
         } => {
             let mut param = BTreeMap::new();
             param.insert("event_type", CANCEL_ORDER_ENTRY_NAME.to_string());
+            param.insert(ORDER_ID_RUNTIME_ARG_NAME, order_id.to_string());
             param.insert(OFFERER_RUNTIME_ARG_NAME, offerer.to_string());
             param.insert(COLLECTION_RUNTIME_ARG_NAME, collection.to_string());
             param.insert(TOKEN_ID_RUNTIME_ARG_NAME, token_id.to_string());
 
             events.push(param);
         }
+        Event::OrderExpired {
+            order_id,
+            offerer,
+            collection,
+            token_id,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert("event_type", REMOVE_EXPIRED_ORDER_ENTRY_NAME.to_string());
+            param.insert(ORDER_ID_RUNTIME_ARG_NAME, order_id.to_string());
+            param.insert(OFFERER_RUNTIME_ARG_NAME, offerer.to_string());
+            param.insert(COLLECTION_RUNTIME_ARG_NAME, collection.to_string());
+            param.insert(TOKEN_ID_RUNTIME_ARG_NAME, token_id.to_string());
+            events.push(param);
+        }
+        Event::OrderInvalidated {
+            order_id,
+            offerer,
+            collection,
+            token_id,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert("event_type", "order_invalidated".to_string());
+            param.insert(ORDER_ID_RUNTIME_ARG_NAME, order_id.to_string());
+            param.insert(OFFERER_RUNTIME_ARG_NAME, offerer.to_string());
+            param.insert(COLLECTION_RUNTIME_ARG_NAME, collection.to_string());
+            param.insert(TOKEN_ID_RUNTIME_ARG_NAME, token_id.to_string());
+            events.push(param);
+        }
+        Event::OrderPriceChanged {
+            order_id,
+            offerer,
+            collection,
+            token_id,
+            price,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert("event_type", CHANGE_ORDER_PRICE_ENTRY_NAME.to_string());
+            param.insert(ORDER_ID_RUNTIME_ARG_NAME, order_id.to_string());
+            param.insert(OFFERER_RUNTIME_ARG_NAME, offerer.to_string());
+            param.insert(COLLECTION_RUNTIME_ARG_NAME, collection.to_string());
+            param.insert(TOKEN_ID_RUNTIME_ARG_NAME, token_id.to_string());
+            param.insert(PRICE_RUNTIME_ARG_NAME, price.to_string());
+            events.push(param);
+        }
+        Event::BundleCreated {
+            offerer,
+            bundle_id,
+            price,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert("event_type", CREATE_BUNDLE_ENTRY_NAME.to_string());
+            param.insert(OFFERER_RUNTIME_ARG_NAME, offerer.to_string());
+            param.insert(BUNDLE_ID_RUNTIME_ARG_NAME, bundle_id.to_string());
+            param.insert(PRICE_RUNTIME_ARG_NAME, price.to_string());
+            events.push(param);
+        }
+        Event::BundleBought {
+            seller,
+            buyer,
+            bundle_id,
+            price,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert("event_type", BUY_BUNDLE_ENTRY_NAME.to_string());
+            param.insert(SELLER_RUNTIME_ARG_NAME, seller.to_string());
+            param.insert(BUYER_RUNTIME_ARG_NAME, buyer.to_string());
+            param.insert(BUNDLE_ID_RUNTIME_ARG_NAME, bundle_id.to_string());
+            param.insert(PRICE_RUNTIME_ARG_NAME, price.to_string());
+            events.push(param);
+        }
+        Event::BundleCanceled { offerer, bundle_id } => {
+            let mut param = BTreeMap::new();
+            param.insert("event_type", CANCEL_BUNDLE_ENTRY_NAME.to_string());
+            param.insert(OFFERER_RUNTIME_ARG_NAME, offerer.to_string());
+            param.insert(BUNDLE_ID_RUNTIME_ARG_NAME, bundle_id.to_string());
+            events.push(param);
+        }
         Event::OfferCreated {
             offerer,
             collection,
@@ -113,6 +344,7 @@ pub(crate) fn emit(event: &Event) {
         }
         Event::OfferAccepted {
             offerer,
+            bidder,
             collection,
             token_id,
             price,
@@ -120,6 +352,7 @@ pub(crate) fn emit(event: &Event) {
             let mut param = BTreeMap::new();
             param.insert("event_type", ACCEPT_OFFER_ENTRY_NAME.to_string());
             param.insert(OFFERER_RUNTIME_ARG_NAME, offerer.to_string());
+            param.insert(BIDDER_RUNTIME_ARG_NAME, bidder.to_string());
             param.insert(COLLECTION_RUNTIME_ARG_NAME, collection.to_string());
             param.insert(TOKEN_ID_RUNTIME_ARG_NAME, token_id.to_string());
             param.insert(PRICE_RUNTIME_ARG_NAME, price.to_string());
@@ -138,6 +371,231 @@ pub(crate) fn emit(event: &Event) {
 
             events.push(param);
         }
+        Event::OfferRejected {
+            offerer,
+            bidder,
+            collection,
+            token_id,
+            price,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert("event_type", REJECT_OFFER_ENTRY_NAME.to_string());
+            param.insert(OFFERER_RUNTIME_ARG_NAME, offerer.to_string());
+            param.insert(BIDDER_RUNTIME_ARG_NAME, bidder.to_string());
+            param.insert(COLLECTION_RUNTIME_ARG_NAME, collection.to_string());
+            param.insert(TOKEN_ID_RUNTIME_ARG_NAME, token_id.to_string());
+            param.insert(PRICE_RUNTIME_ARG_NAME, price.to_string());
+            events.push(param);
+        }
+        Event::OfferExpired {
+            offerer,
+            collection,
+            token_id,
+            price,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert("event_type", RECLAIM_EXPIRED_OFFERS_ENTRY_NAME.to_string());
+            param.insert(OFFERER_RUNTIME_ARG_NAME, offerer.to_string());
+            param.insert(COLLECTION_RUNTIME_ARG_NAME, collection.to_string());
+            param.insert(TOKEN_ID_RUNTIME_ARG_NAME, token_id.to_string());
+            param.insert(PRICE_RUNTIME_ARG_NAME, price.to_string());
+
+            events.push(param);
+        }
+        Event::CollectionOfferCreated {
+            offerer,
+            collection_offer_id,
+            collection,
+            price,
+            quantity,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert("event_type", CREATE_COLLECTION_OFFER_ENTRY_NAME.to_string());
+            param.insert(OFFERER_RUNTIME_ARG_NAME, offerer.to_string());
+            param.insert(
+                COLLECTION_OFFER_ID_RUNTIME_ARG_NAME,
+                collection_offer_id.to_string(),
+            );
+            param.insert(COLLECTION_RUNTIME_ARG_NAME, collection.to_string());
+            param.insert(PRICE_RUNTIME_ARG_NAME, price.to_string());
+            param.insert(QUANTITY_RUNTIME_ARG_NAME, quantity.to_string());
+            events.push(param);
+        }
+        Event::CollectionOfferAccepted {
+            seller,
+            buyer,
+            collection_offer_id,
+            collection,
+            token_id,
+            price,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert("event_type", ACCEPT_COLLECTION_OFFER_ENTRY_NAME.to_string());
+            param.insert(SELLER_RUNTIME_ARG_NAME, seller.to_string());
+            param.insert(BUYER_RUNTIME_ARG_NAME, buyer.to_string());
+            param.insert(
+                COLLECTION_OFFER_ID_RUNTIME_ARG_NAME,
+                collection_offer_id.to_string(),
+            );
+            param.insert(COLLECTION_RUNTIME_ARG_NAME, collection.to_string());
+            param.insert(TOKEN_ID_RUNTIME_ARG_NAME, token_id.to_string());
+            param.insert(PRICE_RUNTIME_ARG_NAME, price.to_string());
+            events.push(param);
+        }
+        Event::CollectionOfferCanceled {
+            offerer,
+            collection_offer_id,
+            refund,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert("event_type", CANCEL_COLLECTION_OFFER_ENTRY_NAME.to_string());
+            param.insert(OFFERER_RUNTIME_ARG_NAME, offerer.to_string());
+            param.insert(
+                COLLECTION_OFFER_ID_RUNTIME_ARG_NAME,
+                collection_offer_id.to_string(),
+            );
+            param.insert(REFUND_RUNTIME_ARG_NAME, refund.to_string());
+            events.push(param);
+        }
+        Event::AuctionCreated {
+            offerer,
+            collection,
+            token_id,
+            auction_type,
+            start_time,
+            end_time,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert("event_type", CREATE_AUCTION_ENTRY_NAME.to_string());
+            param.insert(OFFERER_RUNTIME_ARG_NAME, offerer.to_string());
+            param.insert(COLLECTION_RUNTIME_ARG_NAME, collection.to_string());
+            param.insert(TOKEN_ID_RUNTIME_ARG_NAME, token_id.to_string());
+            param.insert(
+                AUCTION_TYPE_RUNTIME_ARG_NAME,
+                (*auction_type as u8).to_string(),
+            );
+            param.insert(START_TIME_RUNTIME_ARG_NAME, start_time.to_string());
+            if let Some(end_time) = end_time {
+                param.insert(END_TIME_RUNTIME_ARG_NAME, end_time.to_string());
+            }
+            events.push(param);
+        }
+        Event::BidPlaced {
+            bidder,
+            collection,
+            token_id,
+            price,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert("event_type", PLACE_BID_ENTRY_NAME.to_string());
+            param.insert(BIDDER_RUNTIME_ARG_NAME, bidder.to_string());
+            param.insert(COLLECTION_RUNTIME_ARG_NAME, collection.to_string());
+            param.insert(TOKEN_ID_RUNTIME_ARG_NAME, token_id.to_string());
+            param.insert(PRICE_RUNTIME_ARG_NAME, price.to_string());
+            events.push(param);
+        }
+        Event::BidCommitted {
+            bidder,
+            collection,
+            token_id,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert("event_type", COMMIT_BID_ENTRY_NAME.to_string());
+            param.insert(BIDDER_RUNTIME_ARG_NAME, bidder.to_string());
+            param.insert(COLLECTION_RUNTIME_ARG_NAME, collection.to_string());
+            param.insert(TOKEN_ID_RUNTIME_ARG_NAME, token_id.to_string());
+            events.push(param);
+        }
+        Event::BidRevealed {
+            bidder,
+            collection,
+            token_id,
+            price,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert("event_type", REVEAL_BID_ENTRY_NAME.to_string());
+            param.insert(BIDDER_RUNTIME_ARG_NAME, bidder.to_string());
+            param.insert(COLLECTION_RUNTIME_ARG_NAME, collection.to_string());
+            param.insert(TOKEN_ID_RUNTIME_ARG_NAME, token_id.to_string());
+            param.insert(PRICE_RUNTIME_ARG_NAME, price.to_string());
+            events.push(param);
+        }
+        Event::AuctionSettled {
+            offerer,
+            bidder,
+            collection,
+            token_id,
+            price,
+            status,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert("event_type", SETTLE_AUCTION_ENTRY_NAME.to_string());
+            param.insert(OFFERER_RUNTIME_ARG_NAME, offerer.to_string());
+            if let Some(bidder) = bidder {
+                param.insert(BIDDER_RUNTIME_ARG_NAME, bidder.to_string());
+            }
+            param.insert(COLLECTION_RUNTIME_ARG_NAME, collection.to_string());
+            param.insert(TOKEN_ID_RUNTIME_ARG_NAME, token_id.to_string());
+            param.insert(PRICE_RUNTIME_ARG_NAME, price.to_string());
+            param.insert("status", (*status as u8).to_string());
+            events.push(param);
+        }
+        Event::AuctionCanceled {
+            offerer,
+            collection,
+            token_id,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert("event_type", CANCEL_AUCTION_ENTRY_NAME.to_string());
+            param.insert(OFFERER_RUNTIME_ARG_NAME, offerer.to_string());
+            param.insert(COLLECTION_RUNTIME_ARG_NAME, collection.to_string());
+            param.insert(TOKEN_ID_RUNTIME_ARG_NAME, token_id.to_string());
+            events.push(param);
+        }
+        Event::AuctionExtended {
+            collection,
+            token_id,
+            end_time,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert("event_type", "auction_extended".to_string());
+            param.insert(COLLECTION_RUNTIME_ARG_NAME, collection.to_string());
+            param.insert(TOKEN_ID_RUNTIME_ARG_NAME, token_id.to_string());
+            param.insert(END_TIME_RUNTIME_ARG_NAME, end_time.to_string());
+            events.push(param);
+        }
+        Event::AntiSnipingChanged {
+            extension_window,
+            extension_duration,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert("event_type", SET_ANTI_SNIPING_ENTRY_NAME.to_string());
+            param.insert(
+                EXTENSION_WINDOW_RUNTIME_ARG_NAME,
+                extension_window.to_string(),
+            );
+            param.insert(
+                EXTENSION_DURATION_RUNTIME_ARG_NAME,
+                extension_duration.to_string(),
+            );
+            events.push(param);
+        }
+        Event::MinBidIncrementChanged {
+            min_bid_increment,
+            min_bid_increment_rate,
+        } => {
+            let mut param = BTreeMap::new();
+            param.insert("event_type", SET_MIN_BID_INCREMENT_ENTRY_NAME.to_string());
+            param.insert(
+                MIN_BID_INCREMENT_RUNTIME_ARG_NAME,
+                min_bid_increment.to_string(),
+            );
+            param.insert(
+                MIN_BID_INCREMENT_RATE_RUNTIME_ARG_NAME,
+                min_bid_increment_rate.to_string(),
+            );
+            events.push(param);
+        }
         Event::TreasuryWalletChanged { treasury_wallet } => {
             let mut param = BTreeMap::new();
             param.insert("event_type", SET_TREASURY_WALLET_ENTRY_NAME.to_string());
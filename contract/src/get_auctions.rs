@@ -0,0 +1,62 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+#[allow(dead_code)]
+#[path = "structs/mod.rs"]
+mod structs;
+
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs};
+
+use structs::auction::Auction;
+
+const MARKETPLACE_CONTRACT_HASH_ARG_NAME: &str = "marketplace_contract_hash";
+const ENTRY_POINT_RUNTIME_ARG_NAME: &str = "entry_point";
+const COLLECTION_RUNTIME_ARG_NAME: &str = "collection";
+const SELLER_RUNTIME_ARG_NAME: &str = "seller";
+const OFFSET_RUNTIME_ARG_NAME: &str = "offset";
+const LIMIT_RUNTIME_ARG_NAME: &str = "limit";
+const AUCTIONS_KEY_NAME: &str = "auctions";
+
+#[no_mangle]
+fn call() {
+    let entry_point: String = runtime::get_named_arg(ENTRY_POINT_RUNTIME_ARG_NAME);
+    let collection: Option<Key> = runtime::get_named_arg(COLLECTION_RUNTIME_ARG_NAME);
+    let seller: Option<Key> = runtime::get_named_arg(SELLER_RUNTIME_ARG_NAME);
+    let offset: u64 = runtime::get_named_arg(OFFSET_RUNTIME_ARG_NAME);
+    let limit: u64 = runtime::get_named_arg(LIMIT_RUNTIME_ARG_NAME);
+
+    let marketplace_contract_hash: ContractHash = {
+        let ido_contract_hash_key: Key = runtime::get_named_arg(MARKETPLACE_CONTRACT_HASH_ARG_NAME);
+        ido_contract_hash_key
+            .into_hash()
+            .map(ContractHash::new)
+            .unwrap()
+    };
+
+    let mut args = runtime_args! {
+        OFFSET_RUNTIME_ARG_NAME => offset,
+        LIMIT_RUNTIME_ARG_NAME => limit,
+    };
+    if let Some(collection) = collection {
+        args.insert(COLLECTION_RUNTIME_ARG_NAME, collection)
+            .unwrap_or_revert();
+    }
+    if let Some(seller) = seller {
+        args.insert(SELLER_RUNTIME_ARG_NAME, seller)
+            .unwrap_or_revert();
+    }
+
+    // Keep the returned page under the caller's account so it can be read back
+    let auctions: Vec<Auction> =
+        runtime::call_contract(marketplace_contract_hash, &entry_point, args);
+    runtime::put_key(AUCTIONS_KEY_NAME, storage::new_uref(auctions).into());
+}
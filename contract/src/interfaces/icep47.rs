@@ -1,8 +1,10 @@
 #![allow(dead_code)]
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
 use casper_contract::contract_api::runtime;
 use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs, U256};
 
+pub type Meta = BTreeMap<String, String>;
+
 pub struct ICEP47 {
     pub contract_hash: ContractHash,
 }
@@ -53,6 +55,16 @@ impl ICEP47 {
         )
     }
 
+    pub fn token_meta(&self, token_id: U256) -> Meta {
+        runtime::call_contract(
+            self.contract_hash,
+            "token_meta",
+            runtime_args! {
+              "token_id" => token_id
+            },
+        )
+    }
+
     pub fn transfer(&self, recipient: Key, token_ids: Vec<U256>) {
         runtime::call_contract::<()>(
             self.contract_hash,
@@ -7,12 +7,14 @@ compile_error!("target arch should be wasm32: compile with '--target wasm32-unkn
 extern crate alloc;
 
 use alloc::{
-    collections::BTreeSet,
+    collections::{BTreeMap, BTreeSet},
     string::{String, ToString},
     vec,
     vec::Vec,
 };
 
+use anti_sniping::AntiSniping;
+use bid_increment::MinBidIncrement;
 use casper_contract::{
     contract_api::{runtime, storage, system},
     unwrap_or_revert::UnwrapOrRevert,
@@ -23,26 +25,47 @@ use casper_types::{
 };
 use constants::{
     ACCEESS_UREF_KEY_NAME, ADMINS_GROUP_NAME, ADMINS_RUNTIME_ARG_NAME, AMOUNT_RUNTIME_ARG_NAME,
-    AUCTION_TYPE_RUNTIME_ARG_NAME, BID_ID_RUNTIME_ARG_NAME, COLLECTION_RUNTIME_ARG_NAME,
-    CONSTRUCTOR_ENTRY_NAME, CONTRACT_NAME_KEY_NAME, END_TIME_RUNTIME_ARG_NAME, FEE_KEY_NAME,
-    FEE_RUNTIME_ARG_NAME, ON_OFFERS_KEY_NAME, ON_ORDERS_KEY_NAME, ORDERS_KEY_NAME,
-    PRICE_RUNTIME_ARG_NAME, PURSE_BALANCE_KEY_NAME, START_TIME_RUNTIME_ARG_NAME,
-    TOKEN_ID_RUNTIME_ARG_NAME, TREASURY_WALLET_KEY_NAME, TREASURY_WALLET_RUNTIME_ARG_NAME,
+    ANTI_SNIPING_KEY_NAME, AUCTIONS_KEY_NAME, AUCTION_TYPE_RUNTIME_ARG_NAME,
+    BIDDER_RUNTIME_ARG_NAME, BUNDLES_KEY_NAME, BUNDLE_COUNT_KEY_NAME, BUNDLE_ID_RUNTIME_ARG_NAME,
+    COLLECTIONS_RUNTIME_ARG_NAME, COLLECTION_OFFERS_KEY_NAME, COLLECTION_OFFER_COUNT_KEY_NAME,
+    COLLECTION_OFFER_ID_RUNTIME_ARG_NAME, COLLECTION_RUNTIME_ARG_NAME, COMMITMENT_RUNTIME_ARG_NAME,
+    CONSTRUCTOR_ENTRY_NAME, CONTRACT_NAME_KEY_NAME, CUSTODIAL_RUNTIME_ARG_NAME,
+    END_TIME_RUNTIME_ARG_NAME, EXPECTED_PRICE_RUNTIME_ARG_NAME, EXPIRES_AT_RUNTIME_ARG_NAME,
+    EXTENSION_DURATION_RUNTIME_ARG_NAME, EXTENSION_WINDOW_RUNTIME_ARG_NAME, FEE_KEY_NAME,
+    FEE_RUNTIME_ARG_NAME, FLOOR_PRICE_RUNTIME_ARG_NAME, LIMIT_RUNTIME_ARG_NAME,
+    MIN_BID_INCREMENT_KEY_NAME, MIN_BID_INCREMENT_RATE_RUNTIME_ARG_NAME,
+    MIN_BID_INCREMENT_RUNTIME_ARG_NAME, MIN_PRICE_RUNTIME_ARG_NAME, OFFSET_RUNTIME_ARG_NAME,
+    ON_AUCTIONS_KEY_NAME, ON_OFFERS_KEY_NAME, ON_ORDERS_KEY_NAME, ORDERS_KEY_NAME,
+    ORDERS_RUNTIME_ARG_NAME, ORDER_COUNT_KEY_NAME, ORDER_IDS_KEY_NAME, ORDER_ID_RUNTIME_ARG_NAME,
+    PRICES_RUNTIME_ARG_NAME, PRICE_RUNTIME_ARG_NAME, PURSE_BALANCE_KEY_NAME,
+    QUANTITY_RUNTIME_ARG_NAME, RESERVED_FOR_RUNTIME_ARG_NAME, REVEAL_END_TIME_RUNTIME_ARG_NAME,
+    SALT_RUNTIME_ARG_NAME, SECOND_PRICE_RUNTIME_ARG_NAME, SELLER_RUNTIME_ARG_NAME,
+    START_TIME_RUNTIME_ARG_NAME, TOKEN_IDS_RUNTIME_ARG_NAME, TOKEN_ID_RUNTIME_ARG_NAME,
+    TRAITS_RUNTIME_ARG_NAME, TREASURY_WALLET_KEY_NAME, TREASURY_WALLET_RUNTIME_ARG_NAME,
 };
 use detail::store_result;
 use error::Error;
 use event::Event;
-use interfaces::icep47::ICEP47;
+use interfaces::icep47::{Meta, ICEP47};
+use on_auction::OnAuction;
 use on_offers::OnOffer;
 use on_orders::OnOrder;
 use structs::{
-    auction::{Auction, AuctionType},
+    auction::{Auction, AuctionStatus, AuctionType},
     bid::{Bid, BidStatus},
+    bundle::Bundle,
+    collection_offer::CollectionOffer,
     offer::Offer,
     order::Order,
+    sealed_bid::SealedBid,
 };
 
 mod address;
+mod anti_sniping;
+mod auctions;
+mod bid_increment;
+mod bundles;
+mod collection_offers;
 mod constants;
 mod detail;
 mod entry_points;
@@ -94,6 +117,29 @@ pub extern "C" fn set_fee() {
     event::emit(&Event::FeeChanged { fee });
 }
 
+#[no_mangle]
+pub extern "C" fn set_anti_sniping() {
+    let extension_window: u64 = runtime::get_named_arg(EXTENSION_WINDOW_RUNTIME_ARG_NAME);
+    let extension_duration: u64 = runtime::get_named_arg(EXTENSION_DURATION_RUNTIME_ARG_NAME);
+    anti_sniping::write_anti_sniping((extension_window, extension_duration));
+    event::emit(&Event::AntiSnipingChanged {
+        extension_window,
+        extension_duration,
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn set_min_bid_increment() {
+    let min_bid_increment: U512 = runtime::get_named_arg(MIN_BID_INCREMENT_RUNTIME_ARG_NAME);
+    let min_bid_increment_rate: U512 =
+        runtime::get_named_arg(MIN_BID_INCREMENT_RATE_RUNTIME_ARG_NAME);
+    bid_increment::write_min_bid_increment((min_bid_increment, min_bid_increment_rate));
+    event::emit(&Event::MinBidIncrementChanged {
+        min_bid_increment,
+        min_bid_increment_rate,
+    });
+}
+
 #[no_mangle]
 pub extern "C" fn create_order() {
     let collection: ContractHash = {
@@ -102,6 +148,16 @@ pub extern "C" fn create_order() {
     };
     let token_id: U256 = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
     let price: U512 = runtime::get_named_arg(PRICE_RUNTIME_ARG_NAME);
+    let expires_at: Option<u64> = runtime::get_named_arg(EXPIRES_AT_RUNTIME_ARG_NAME);
+    let reserved_for: Option<AccountHash> = runtime::get_named_arg(RESERVED_FOR_RUNTIME_ARG_NAME);
+    // Non-custodial listings leave the token with the seller under an approval
+    let custodial: bool = runtime::get_named_arg(CUSTODIAL_RUNTIME_ARG_NAME);
+
+    if let Some(expires_at) = expires_at {
+        if expires_at <= u64::from(runtime::get_blocktime()) {
+            runtime::revert(Error::OrderExpired);
+        }
+    }
 
     let mut on_orders = on_orders::read_on_orders();
 
@@ -133,37 +189,215 @@ pub extern "C" fn create_order() {
         runtime::revert(Error::NotApproved);
     }
 
-    ICEP47::new(collection).transfer_from(
-        Key::from(runtime::get_caller()),
-        Key::from(me),
-        vec![token_id],
-    );
+    if custodial {
+        ICEP47::new(collection).transfer_from(
+            Key::from(runtime::get_caller()),
+            Key::from(me),
+            vec![token_id],
+        );
 
-    let token_owner = ICEP47::new(collection).owner_of(token_id);
+        let token_owner = ICEP47::new(collection).owner_of(token_id);
 
-    if token_owner != Some(Key::from(me)) {
-        runtime::revert(Error::NotOwner);
+        if token_owner != Some(Key::from(me)) {
+            runtime::revert(Error::NotOwner);
+        }
+    } else {
+        let token_owner = ICEP47::new(collection).owner_of(token_id);
+
+        if token_owner != Some(Key::from(offerer)) {
+            runtime::revert(Error::NotOwner);
+        }
     }
 
     let is_active = true;
     let order = Order {
+        order_id: orders::next_order_id(),
         collection,
         token_id,
         offerer,
         price,
         is_active,
+        buyer: None,
+        sold_at: None,
+        expires_at,
+        reserved_for,
+        custodial,
+        is_valid: true,
     };
     orders::write_order(order);
     event::emit(&Event::OrderCreated {
+        order_id: order.order_id,
         offerer,
         collection: collection.into(),
         token_id,
         price,
+        reserved_for,
     });
 }
 
 #[no_mangle]
-pub extern "C" fn change_order_price() {}
+pub extern "C" fn create_orders() {
+    let collection: ContractHash = {
+        let collection_key: Key = runtime::get_named_arg(COLLECTION_RUNTIME_ARG_NAME);
+        ContractHash::new(collection_key.into_hash().unwrap())
+    };
+    let token_ids: Vec<U256> = runtime::get_named_arg(TOKEN_IDS_RUNTIME_ARG_NAME);
+    let prices: Vec<U512> = runtime::get_named_arg(PRICES_RUNTIME_ARG_NAME);
+    let expires_at: Option<u64> = runtime::get_named_arg(EXPIRES_AT_RUNTIME_ARG_NAME);
+
+    if token_ids.is_empty() || token_ids.len() != prices.len() {
+        runtime::revert(Error::InvalidBatch);
+    }
+    if let Some(expires_at) = expires_at {
+        if expires_at <= u64::from(runtime::get_blocktime()) {
+            runtime::revert(Error::OrderExpired);
+        }
+    }
+
+    let offerer = runtime::get_caller();
+
+    let me = detail::get_caller_address()
+        .unwrap()
+        .as_contract_package_hash()
+        .unwrap()
+        .clone();
+
+    // Every listing is checked before any token moves, so the batch lists all or nothing
+    let mut on_orders = on_orders::read_on_orders();
+    for token_id in token_ids.iter() {
+        if on_orders
+            .iter()
+            .any(|on_order| on_order.0.eq(&collection) && on_order.1.eq(token_id))
+        {
+            runtime::revert(Error::OrderExist);
+        }
+        let approved = ICEP47::new(collection).get_approved(Key::from(offerer), *token_id);
+        if approved != Some(Key::from(me)) {
+            runtime::revert(Error::NotApproved);
+        }
+        on_orders.push((collection, *token_id));
+    }
+    on_orders::write_on_orders(on_orders);
+
+    ICEP47::new(collection).transfer_from(Key::from(offerer), Key::from(me), token_ids.clone());
+
+    for (token_id, price) in token_ids.into_iter().zip(prices.into_iter()) {
+        let token_owner = ICEP47::new(collection).owner_of(token_id);
+        if token_owner != Some(Key::from(me)) {
+            runtime::revert(Error::NotOwner);
+        }
+
+        let order = Order {
+            order_id: orders::next_order_id(),
+            collection,
+            token_id,
+            offerer,
+            price,
+            is_active: true,
+            buyer: None,
+            sold_at: None,
+            expires_at,
+            reserved_for: None,
+            custodial: true,
+            is_valid: true,
+        };
+        orders::write_order(order);
+        event::emit(&Event::OrderCreated {
+            order_id: order.order_id,
+            offerer,
+            collection: collection.into(),
+            token_id,
+            price,
+            reserved_for: None,
+        });
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_orders() {
+    let collection: ContractHash = {
+        let collection_key: Key = runtime::get_named_arg(COLLECTION_RUNTIME_ARG_NAME);
+        ContractHash::new(collection_key.into_hash().unwrap())
+    };
+    let token_ids: Vec<U256> = runtime::get_named_arg(TOKEN_IDS_RUNTIME_ARG_NAME);
+    if token_ids.is_empty() {
+        runtime::revert(Error::InvalidBatch);
+    }
+    let caller = runtime::get_caller();
+
+    let mut on_orders: Vec<OnOrder> = on_orders::read_on_orders();
+    let mut escrowed_token_ids: Vec<U256> = Vec::new();
+    let mut canceled_orders: Vec<Order> = Vec::new();
+    for token_id in token_ids.iter() {
+        let index = on_orders
+            .iter()
+            .position(|on_order| on_order.0.eq(&collection) && on_order.1.eq(token_id))
+            .unwrap_or_revert_with(Error::OrderNotExist);
+        let mut order = orders::read_order(collection, *token_id);
+        if caller != order.offerer {
+            runtime::revert(Error::NotOrderMaker);
+        }
+        if order.custodial {
+            escrowed_token_ids.push(*token_id);
+        }
+        order.is_active = false;
+        orders::write_order(order);
+        on_orders.remove(index);
+        canceled_orders.push(order);
+    }
+    on_orders::write_on_orders(on_orders);
+
+    // Refund the escrowed tokens
+    if !escrowed_token_ids.is_empty() {
+        ICEP47::new(collection).transfer(Key::from(caller), escrowed_token_ids);
+    }
+
+    for order in canceled_orders {
+        event::emit(&Event::OrderCanceled {
+            order_id: order.order_id,
+            offerer: caller,
+            collection: collection.into(),
+            token_id: order.token_id,
+        });
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn change_order_price() {
+    let collection: ContractHash = {
+        let collection_key: Key = runtime::get_named_arg(COLLECTION_RUNTIME_ARG_NAME);
+        ContractHash::new(collection_key.into_hash().unwrap())
+    };
+    let token_id: U256 = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let price: U512 = runtime::get_named_arg(PRICE_RUNTIME_ARG_NAME);
+    let find_result = on_orders::find(collection, token_id);
+    if find_result == None {
+        runtime::revert(Error::OrderNotExist);
+    }
+    let mut order = orders::read_order(collection, token_id);
+    let caller = runtime::get_caller();
+    if caller != order.offerer {
+        runtime::revert(Error::NotOrderMaker);
+    }
+    if order.is_expired(u64::from(runtime::get_blocktime())) {
+        runtime::revert(Error::OrderExpired);
+    }
+    if price.is_zero() {
+        runtime::revert(Error::NotValidAmount);
+    }
+
+    order.price = price;
+    orders::write_order(order);
+
+    store_result(order);
+    event::emit(&Event::OrderPriceChanged {
+        order_id: order.order_id,
+        offerer: caller,
+        collection: collection.into(),
+        token_id,
+        price,
+    });
+}
 
 #[no_mangle]
 pub extern "C" fn cancel_order() {
@@ -183,7 +417,9 @@ pub extern "C" fn cancel_order() {
     }
 
     // Refund the token
-    ICEP47::new(order.collection).transfer(Key::from(order.offerer), vec![order.token_id]);
+    if order.custodial {
+        ICEP47::new(order.collection).transfer(Key::from(order.offerer), vec![order.token_id]);
+    }
     order.is_active = false;
     orders::write_order(order);
 
@@ -193,12 +429,57 @@ pub extern "C" fn cancel_order() {
 
     store_result(order);
     event::emit(&Event::OrderCanceled {
+        order_id: order.order_id,
         offerer: caller,
         collection: collection.into(),
         token_id,
     });
 }
 
+#[no_mangle]
+pub extern "C" fn remove_expired_order() {
+    let collection: ContractHash = {
+        let collection_key: Key = runtime::get_named_arg(COLLECTION_RUNTIME_ARG_NAME);
+        ContractHash::new(collection_key.into_hash().unwrap())
+    };
+    let token_id: U256 = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let find_result = on_orders::find(collection, token_id);
+    if find_result == None {
+        runtime::revert(Error::OrderNotExist);
+    }
+    let mut order = orders::read_order(collection, token_id);
+    // Anyone may clean up a listing once it has expired
+    if !order.is_expired(u64::from(runtime::get_blocktime())) {
+        runtime::revert(Error::OrderNotExpired);
+    }
+
+    // Refund the token
+    if order.custodial {
+        ICEP47::new(order.collection).transfer(Key::from(order.offerer), vec![order.token_id]);
+    }
+    order.is_active = false;
+    orders::write_order(order);
+
+    let mut on_orders: Vec<OnOrder> = on_orders::read_on_orders();
+    on_orders.remove(find_result.unwrap());
+    on_orders::write_on_orders(on_orders);
+
+    store_result(order);
+    event::emit(&Event::OrderExpired {
+        order_id: order.order_id,
+        offerer: order.offerer,
+        collection: collection.into(),
+        token_id,
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn get_order() {
+    let order_id: u64 = runtime::get_named_arg(ORDER_ID_RUNTIME_ARG_NAME);
+    let order = orders::read_order_by_id(order_id).unwrap_or_revert_with(Error::OrderNotExist);
+    runtime::ret(CLValue::from_t(order).unwrap_or_revert());
+}
+
 #[no_mangle]
 pub extern "C" fn buy_order() {
     let amount: U512 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
@@ -207,6 +488,7 @@ pub extern "C" fn buy_order() {
         ContractHash::new(collection_key.into_hash().unwrap())
     };
     let token_id: U256 = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let expected_price: U512 = runtime::get_named_arg(EXPECTED_PRICE_RUNTIME_ARG_NAME);
 
     let find_result = on_orders::find(collection, token_id);
     if find_result == None {
@@ -214,32 +496,216 @@ pub extern "C" fn buy_order() {
     }
 
     let _ = purse::checked_balance();
-    let mut order = orders::read_order(collection, token_id);
-    if !amount.eq(&order.price) {
+    let order = orders::read_order(collection, token_id);
+    if order.is_expired(u64::from(runtime::get_blocktime())) {
+        runtime::revert(Error::OrderExpired);
+    }
+
+    if !is_order_backed(&order) {
+        // Refund the buyer and drop the stale listing
+        purse::transfer(runtime::get_caller(), amount);
+        invalidate_order(order);
+        return;
+    }
+
+    // The seller may have repriced after the buyer saw the order
+    if order.price > expected_price {
+        runtime::revert(Error::UnexpectedPrice);
+    }
+    if amount < order.price {
+        runtime::revert(Error::NotValidAmount);
+    }
+
+    let buyer = runtime::get_caller();
+    if buyer == order.offerer {
+        runtime::revert(Error::PermissionDenied);
+    }
+    if order
+        .reserved_for
+        .map_or(false, |reserved_for| reserved_for != buyer)
+    {
+        runtime::revert(Error::NotReservedBuyer);
+    }
+
+    // Refund the overpayment to buyer
+    let refund = amount.checked_sub(order.price).unwrap_or_revert();
+    if !refund.is_zero() {
+        purse::transfer(buyer, refund);
+    }
+    sell_order(order, buyer, refund);
+}
+
+#[no_mangle]
+pub extern "C" fn buy_orders() {
+    let amount: U512 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let listings: Vec<(Key, U256, U512)> = runtime::get_named_arg(ORDERS_RUNTIME_ARG_NAME);
+    if listings.is_empty() {
+        runtime::revert(Error::InvalidBatch);
+    }
+
+    let _ = purse::checked_balance();
+    let buyer = runtime::get_caller();
+    let now = u64::from(runtime::get_blocktime());
+
+    let mut remaining = amount;
+    for (collection_key, token_id, max_price) in listings {
+        let collection = ContractHash::new(collection_key.into_hash().unwrap());
+        // Listings sold or canceled since the sweep was signed are skipped
+        if on_orders::find(collection, token_id) == None {
+            continue;
+        }
+        let order = orders::read_order(collection, token_id);
+        if order.is_expired(now)
+            || order.offerer == buyer
+            || order
+                .reserved_for
+                .map_or(false, |reserved_for| reserved_for != buyer)
+            || order.price > max_price
+            || order.price > remaining
+        {
+            continue;
+        }
+        if !is_order_backed(&order) {
+            invalidate_order(order);
+            continue;
+        }
+        remaining = remaining.checked_sub(order.price).unwrap_or_revert();
+        sell_order(order, buyer, U512::zero());
+    }
+
+    // Refund the unspent deposit
+    if !remaining.is_zero() {
+        purse::transfer(buyer, remaining);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn create_bundle() {
+    let collections: Vec<ContractHash> = {
+        let collection_keys: Vec<Key> = runtime::get_named_arg(COLLECTIONS_RUNTIME_ARG_NAME);
+        collection_keys
+            .into_iter()
+            .map(|collection_key| ContractHash::new(collection_key.into_hash().unwrap()))
+            .collect()
+    };
+    let token_ids: Vec<U256> = runtime::get_named_arg(TOKEN_IDS_RUNTIME_ARG_NAME);
+    let price: U512 = runtime::get_named_arg(PRICE_RUNTIME_ARG_NAME);
+
+    if token_ids.is_empty() || token_ids.len() != collections.len() {
+        runtime::revert(Error::InvalidBatch);
+    }
+
+    let offerer = runtime::get_caller();
+
+    let me = detail::get_caller_address()
+        .unwrap()
+        .as_contract_package_hash()
+        .unwrap()
+        .clone();
+
+    let tokens: Vec<(ContractHash, U256)> = collections.into_iter().zip(token_ids).collect();
+
+    // Escrow every token, one transfer per collection
+    for (collection, token_id) in tokens.iter() {
+        let approved = ICEP47::new(*collection).get_approved(Key::from(offerer), *token_id);
+        if approved != Some(Key::from(me)) {
+            runtime::revert(Error::NotApproved);
+        }
+    }
+    for (collection, token_ids) in group_by_collection(&tokens) {
+        ICEP47::new(collection).transfer_from(Key::from(offerer), Key::from(me), token_ids);
+    }
+    for (collection, token_id) in tokens.iter() {
+        let token_owner = ICEP47::new(*collection).owner_of(*token_id);
+        if token_owner != Some(Key::from(me)) {
+            runtime::revert(Error::NotOwner);
+        }
+    }
+
+    let bundle_id = bundles::next_bundle_id();
+    let bundle = Bundle {
+        bundle_id,
+        offerer,
+        tokens,
+        price,
+        is_active: true,
+        buyer: None,
+        sold_at: None,
+    };
+    store_result(bundle.clone());
+    bundles::write_bundle(bundle);
+    event::emit(&Event::BundleCreated {
+        offerer,
+        bundle_id,
+        price,
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn buy_bundle() {
+    let amount: U512 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let bundle_id: u64 = runtime::get_named_arg(BUNDLE_ID_RUNTIME_ARG_NAME);
+
+    let mut bundle = bundles::read_bundle(bundle_id)
+        .filter(|bundle| bundle.is_active)
+        .unwrap_or_revert_with(Error::BundleNotExist);
+
+    let _ = purse::checked_balance();
+    if !amount.eq(&bundle.price) {
         runtime::revert(Error::NotValidAmount);
     }
 
+    let buyer = runtime::get_caller();
+    if buyer == bundle.offerer {
+        runtime::revert(Error::PermissionDenied);
+    }
+
+    bundle.is_active = false;
+    bundle.buyer = Some(buyer);
+    bundle.sold_at = Some(u64::from(runtime::get_blocktime()));
+
+    store_result(bundle.clone());
+    bundles::write_bundle(bundle.clone());
+
+    // Send every NFT to buyer
+    for (collection, token_ids) in group_by_collection(&bundle.tokens) {
+        ICEP47::new(collection).transfer(Key::from(buyer), token_ids);
+    }
+    // Send CSPR to bundle offerer and treasury wallet
+    purse::transfer_with_fee(bundle.offerer, bundle.price);
+    event::emit(&Event::BundleBought {
+        seller: bundle.offerer,
+        buyer,
+        bundle_id,
+        price: bundle.price,
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_bundle() {
+    let bundle_id: u64 = runtime::get_named_arg(BUNDLE_ID_RUNTIME_ARG_NAME);
+
+    let mut bundle = bundles::read_bundle(bundle_id)
+        .filter(|bundle| bundle.is_active)
+        .unwrap_or_revert_with(Error::BundleNotExist);
+
     let caller = runtime::get_caller();
-    if caller != order.offerer {
-        runtime::revert(Error::NotOrderMaker);
+    if caller != bundle.offerer {
+        runtime::revert(Error::PermissionDenied);
     }
 
-    // Send NFT to caller
-    ICEP47::new(order.collection).transfer(Key::from(caller), vec![order.token_id]);
-    // Send CSPR to order offerer and treasury wallet
-    purse::transfer_with_fee(order.offerer, order.price);
+    bundle.is_active = false;
 
-    order.is_active = false;
+    store_result(bundle.clone());
+    bundles::write_bundle(bundle.clone());
 
-    let mut on_orders: Vec<OnOrder> = on_orders::read_on_orders();
-    on_orders.remove(find_result.unwrap());
-    on_orders::write_on_orders(on_orders);
-    orders::write_order(order);
-    event::emit(&Event::OrderBought {
+    // Refund every token
+    for (collection, token_ids) in group_by_collection(&bundle.tokens) {
+        ICEP47::new(collection).transfer(Key::from(caller), token_ids);
+    }
+    event::emit(&Event::BundleCanceled {
         offerer: caller,
-        collection: collection.into(),
-        token_id,
-        price: order.price,
+        bundle_id,
     });
 }
 
@@ -253,6 +719,7 @@ pub extern "C" fn create_offer() {
     let token_id: U256 = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
     let offerer = runtime::get_caller();
     let price: U512 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let expires_at: Option<u64> = runtime::get_named_arg(EXPIRES_AT_RUNTIME_ARG_NAME);
     let bid_time = u64::from(runtime::get_blocktime());
 
     let bid = Bid {
@@ -260,7 +727,11 @@ pub extern "C" fn create_offer() {
         price,
         bid_time,
         status: BidStatus::Pending,
+        expires_at,
     };
+    if bid.is_expired(bid_time) {
+        runtime::revert(Error::OfferExpired);
+    }
 
     let mut offer = offers::read_offer(collection, token_id);
     let find_result = on_offers::find(collection, token_id, offerer);
@@ -327,10 +798,8 @@ pub extern "C" fn cancel_offer() {
 
 #[no_mangle]
 pub extern "C" fn accept_offer() {
-    let bid_id: usize = {
-        let id: u8 = runtime::get_named_arg(BID_ID_RUNTIME_ARG_NAME);
-        usize::from(id)
-    };
+    let bidder: AccountHash = runtime::get_named_arg(BIDDER_RUNTIME_ARG_NAME);
+    let min_price: U512 = runtime::get_named_arg(MIN_PRICE_RUNTIME_ARG_NAME);
     let collection: ContractHash = {
         let collection_key: Key = runtime::get_named_arg(COLLECTION_RUNTIME_ARG_NAME);
         ContractHash::new(collection_key.into_hash().unwrap())
@@ -342,7 +811,26 @@ pub extern "C" fn accept_offer() {
         runtime::revert(Error::PermissionDenied);
     }
     let mut offer = offers::read_offer(collection, token_id);
-    let accepted_bid = offer.bids.get(bid_id).unwrap().clone();
+    let bid_index = offer
+        .get_bid_index_by_account(bidder)
+        .unwrap_or_revert_with(Error::OfferNotExist);
+    let accepted_bid = offer.bids.remove(bid_index);
+    if accepted_bid.price < min_price {
+        runtime::revert(Error::UnexpectedPrice);
+    }
+    if accepted_bid.is_expired(u64::from(runtime::get_blocktime())) {
+        runtime::revert(Error::OfferExpired);
+    }
+
+    // Drop the bid before any external call so a re-entrant accept cannot pay it twice
+    store_result(offer.clone());
+    offers::write_offer(offer);
+
+    let mut on_offers = on_offers::read_on_offers();
+    if let Some(index) = on_offers::find(collection, token_id, bidder) {
+        on_offers.remove(index);
+        on_offers::write_on_offers(on_offers);
+    }
 
     // Send cspr to token owner and transfer nft to bidder
     purse::transfer_with_fee(caller, accepted_bid.price);
@@ -351,11 +839,9 @@ pub extern "C" fn accept_offer() {
         Key::from(accepted_bid.offerer),
         vec![token_id],
     );
-
-    offer.bids.remove(bid_id);
-    offers::write_offer(offer);
     event::emit(&Event::OfferAccepted {
         offerer: caller,
+        bidder,
         collection: collection.into(),
         token_id,
         price: accepted_bid.price,
@@ -363,47 +849,984 @@ pub extern "C" fn accept_offer() {
 }
 
 #[no_mangle]
-pub extern "C" fn constructor() {
-    let purse: URef = system::create_purse();
-    purse::set_main_purse(purse);
-
-    let purse_balance_uref = storage::new_uref(U512::zero());
-    runtime::put_key(PURSE_BALANCE_KEY_NAME, purse_balance_uref.into());
-}
-
-#[no_mangle]
-pub extern "C" fn get_purse() {
-    let purse: URef = purse::get_main_purse();
-    runtime::ret(CLValue::from_t(purse).unwrap_or_revert());
-}
-
-#[no_mangle]
-pub extern "C" fn create_auction() {
-    let offerer = runtime::get_caller();
+pub extern "C" fn reject_offer() {
     let collection: ContractHash = {
         let collection_key: Key = runtime::get_named_arg(COLLECTION_RUNTIME_ARG_NAME);
         ContractHash::new(collection_key.into_hash().unwrap())
     };
     let token_id: U256 = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
-    let auction_type: AuctionType = {
-        let auction_u8: u8 = runtime::get_named_arg(AUCTION_TYPE_RUNTIME_ARG_NAME);
-        AuctionType::from(auction_u8)
-    };
-    let price: Option<U512> = runtime::get_named_arg(PRICE_RUNTIME_ARG_NAME);
-    let start_time: u64 = runtime::get_named_arg(START_TIME_RUNTIME_ARG_NAME);
-    let end_time: Option<u64> = runtime::get_named_arg(END_TIME_RUNTIME_ARG_NAME);
-    let bids: Vec<Bid> = Vec::new();
+    let bidder: AccountHash = runtime::get_named_arg(BIDDER_RUNTIME_ARG_NAME);
+    let caller = runtime::get_caller();
+    let token_owner = ICEP47::new(collection).owner_of(token_id);
+    if token_owner != Some(Key::from(caller)) {
+        runtime::revert(Error::PermissionDenied);
+    }
+
+    let mut offer = offers::read_offer(collection, token_id);
+    let bid_index = offer
+        .get_bid_index_by_account(bidder)
+        .unwrap_or_revert_with(Error::OfferNotExist);
+    let bid = offer.bids.get_mut(bid_index).unwrap();
+    bid.status = BidStatus::NotAccepted;
+    let price = bid.price;
+
+    store_result(offer.clone());
+    offers::write_offer(offer);
+
+    let mut on_offers = on_offers::read_on_offers();
+    if let Some(index) = on_offers::find(collection, token_id, bidder) {
+        on_offers.remove(index);
+        on_offers::write_on_offers(on_offers);
+    }
+
+    //Refund
+    purse::transfer(bidder, price);
+    event::emit(&Event::OfferRejected {
+        offerer: caller,
+        bidder,
+        collection: collection.into(),
+        token_id,
+        price,
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn reclaim_expired_offers() {
+    let collection: ContractHash = {
+        let collection_key: Key = runtime::get_named_arg(COLLECTION_RUNTIME_ARG_NAME);
+        ContractHash::new(collection_key.into_hash().unwrap())
+    };
+    let token_id: U256 = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let current_time = u64::from(runtime::get_blocktime());
+
+    let mut offer = offers::read_offer(collection, token_id);
+    let mut on_offers = on_offers::read_on_offers();
+    let mut expired_bids: Vec<Bid> = Vec::new();
+    for bid in offer.bids.iter_mut() {
+        if bid.status != BidStatus::Pending || !bid.is_expired(current_time) {
+            continue;
+        }
+        let offerer = bid.offerer;
+        bid.status = BidStatus::Canceled;
+        on_offers.retain(|&(c, t, o)| !(c == collection && t == token_id && o == offerer));
+        expired_bids.push(*bid);
+    }
+
+    store_result(offer.clone());
+    offers::write_offer(offer);
+    on_offers::write_on_offers(on_offers);
+    for bid in expired_bids {
+        //Refund
+        purse::transfer(bid.offerer, bid.price);
+        event::emit(&Event::OfferExpired {
+            offerer: bid.offerer,
+            collection: collection.into(),
+            token_id,
+            price: bid.price,
+        });
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn create_collection_offer() {
+    let _ = purse::checked_balance();
+    let collection: ContractHash = {
+        let collection_key: Key = runtime::get_named_arg(COLLECTION_RUNTIME_ARG_NAME);
+        ContractHash::new(collection_key.into_hash().unwrap())
+    };
+    let price: U512 = runtime::get_named_arg(PRICE_RUNTIME_ARG_NAME);
+    let quantity: u64 = runtime::get_named_arg(QUANTITY_RUNTIME_ARG_NAME);
+    let traits: Meta = runtime::get_named_arg(TRAITS_RUNTIME_ARG_NAME);
+    let amount: U512 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+
+    // The deposit must cover every token the offer can buy
+    let total = price
+        .checked_mul(U512::from(quantity))
+        .unwrap_or_revert_with(Error::Overflow);
+    if quantity == 0 || !amount.eq(&total) {
+        runtime::revert(Error::NotValidAmount);
+    }
+
+    let offerer = runtime::get_caller();
+    let collection_offer_id = collection_offers::next_collection_offer_id();
+    let collection_offer = CollectionOffer {
+        collection_offer_id,
+        collection,
+        offerer,
+        price,
+        quantity,
+        filled: 0,
+        bid_time: u64::from(runtime::get_blocktime()),
+        is_active: true,
+        traits,
+    };
+    store_result(collection_offer.clone());
+    collection_offers::write_collection_offer(collection_offer);
+    event::emit(&Event::CollectionOfferCreated {
+        offerer,
+        collection_offer_id,
+        collection: collection.into(),
+        price,
+        quantity,
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_collection_offer() {
+    let collection_offer_id: u64 = runtime::get_named_arg(COLLECTION_OFFER_ID_RUNTIME_ARG_NAME);
+
+    let mut collection_offer = collection_offers::read_collection_offer(collection_offer_id)
+        .filter(|collection_offer| collection_offer.is_active)
+        .unwrap_or_revert_with(Error::CollectionOfferNotExist);
+
+    let caller = runtime::get_caller();
+    if caller != collection_offer.offerer {
+        runtime::revert(Error::PermissionDenied);
+    }
+
+    // Refund the unfilled part of the deposit
+    let refund = collection_offer.price * U512::from(collection_offer.remaining());
+    purse::transfer(caller, refund);
+    collection_offer.is_active = false;
+
+    store_result(collection_offer.clone());
+    collection_offers::write_collection_offer(collection_offer);
+    event::emit(&Event::CollectionOfferCanceled {
+        offerer: caller,
+        collection_offer_id,
+        refund,
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn accept_collection_offer() {
+    let collection_offer_id: u64 = runtime::get_named_arg(COLLECTION_OFFER_ID_RUNTIME_ARG_NAME);
+    let token_id: U256 = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+
+    let mut collection_offer = collection_offers::read_collection_offer(collection_offer_id)
+        .filter(|collection_offer| collection_offer.is_active)
+        .unwrap_or_revert_with(Error::CollectionOfferNotExist);
+
+    let caller = runtime::get_caller();
+    if caller == collection_offer.offerer {
+        runtime::revert(Error::PermissionDenied);
+    }
+    let collection = collection_offer.collection;
+    let token_owner = ICEP47::new(collection).owner_of(token_id).unwrap();
+    if token_owner != Key::from(caller) {
+        runtime::revert(Error::PermissionDenied);
+    }
+    if !collection_offer.matches(&ICEP47::new(collection).token_meta(token_id)) {
+        runtime::revert(Error::TraitMismatch);
+    }
+
+    // Record the fill before any external call so a re-entrant accept sees it
+    collection_offer.filled += 1;
+    if collection_offer.remaining() == 0 {
+        collection_offer.is_active = false;
+    }
+    store_result(collection_offer.clone());
+    collection_offers::write_collection_offer(collection_offer.clone());
+
+    // Send cspr to token owner and transfer nft to offerer
+    purse::transfer_with_fee(caller, collection_offer.price);
+    ICEP47::new(collection).transfer_from(
+        Key::from(caller),
+        Key::from(collection_offer.offerer),
+        vec![token_id],
+    );
+
+    event::emit(&Event::CollectionOfferAccepted {
+        seller: caller,
+        buyer: collection_offer.offerer,
+        collection_offer_id,
+        collection: collection.into(),
+        token_id,
+        price: collection_offer.price,
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn constructor() {
+    let purse: URef = system::create_purse();
+    purse::set_main_purse(purse);
+
+    let purse_balance_uref = storage::new_uref(U512::zero());
+    runtime::put_key(PURSE_BALANCE_KEY_NAME, purse_balance_uref.into());
+}
+
+#[no_mangle]
+pub extern "C" fn get_purse() {
+    let purse: URef = purse::get_main_purse();
+    runtime::ret(CLValue::from_t(purse).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn create_auction() {
+    let offerer = runtime::get_caller();
+    let collection: ContractHash = {
+        let collection_key: Key = runtime::get_named_arg(COLLECTION_RUNTIME_ARG_NAME);
+        ContractHash::new(collection_key.into_hash().unwrap())
+    };
+    let token_id: U256 = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let auction_type: AuctionType = {
+        let auction_u8: u8 = runtime::get_named_arg(AUCTION_TYPE_RUNTIME_ARG_NAME);
+        AuctionType::from(auction_u8)
+    };
+    let price: Option<U512> = runtime::get_named_arg(PRICE_RUNTIME_ARG_NAME);
+    let floor_price: Option<U512> = runtime::get_named_arg(FLOOR_PRICE_RUNTIME_ARG_NAME);
+    let start_time: u64 = runtime::get_named_arg(START_TIME_RUNTIME_ARG_NAME);
+    let end_time: Option<u64> = runtime::get_named_arg(END_TIME_RUNTIME_ARG_NAME);
+    let reveal_end_time: Option<u64> = runtime::get_named_arg(REVEAL_END_TIME_RUNTIME_ARG_NAME);
+    let second_price: bool = runtime::get_named_arg(SECOND_PRICE_RUNTIME_ARG_NAME);
+    let (extension_window, extension_duration) = {
+        let (default_window, default_duration) = anti_sniping::read_anti_sniping();
+        let window: Option<u64> = runtime::get_named_arg(EXTENSION_WINDOW_RUNTIME_ARG_NAME);
+        let duration: Option<u64> = runtime::get_named_arg(EXTENSION_DURATION_RUNTIME_ARG_NAME);
+        (
+            window.unwrap_or(default_window),
+            duration.unwrap_or(default_duration),
+        )
+    };
+    let (min_bid_increment, min_bid_increment_rate) = {
+        let (default_increment, default_rate) = bid_increment::read_min_bid_increment();
+        let increment: Option<U512> = runtime::get_named_arg(MIN_BID_INCREMENT_RUNTIME_ARG_NAME);
+        let rate: Option<U512> = runtime::get_named_arg(MIN_BID_INCREMENT_RATE_RUNTIME_ARG_NAME);
+        (
+            increment.unwrap_or(default_increment),
+            rate.unwrap_or(default_rate),
+        )
+    };
+
+    if let Some(auction) = auctions::read_auction(collection, token_id) {
+        if auction.status == AuctionStatus::Active {
+            runtime::revert(Error::AuctionExist);
+        }
+    }
+
+    let now = u64::from(runtime::get_blocktime());
+    match end_time {
+        Some(end_time) if end_time > start_time && end_time > now => {}
+        _ => runtime::revert(Error::InvalidAuctionTime),
+    }
+
+    match auction_type {
+        AuctionType::Decreasing => match (price, floor_price) {
+            (Some(price), Some(floor_price)) if floor_price <= price => {}
+            _ => runtime::revert(Error::InvalidAuctionPrice),
+        },
+        AuctionType::FixedPrice => match price {
+            Some(price) if !price.is_zero() => {}
+            _ => runtime::revert(Error::InvalidAuctionPrice),
+        },
+        AuctionType::Sealed => match (end_time, reveal_end_time) {
+            (Some(end_time), Some(reveal_end_time)) if reveal_end_time > end_time => {}
+            _ => runtime::revert(Error::InvalidAuctionTime),
+        },
+        _ => {}
+    }
+
+    let me = detail::get_caller_address()
+        .unwrap()
+        .as_contract_package_hash()
+        .unwrap()
+        .clone();
+
+    let approved = ICEP47::new(collection).get_approved(Key::from(offerer), token_id);
+
+    if approved != Some(Key::from(me)) {
+        runtime::revert(Error::NotApproved);
+    }
+
+    // Escrow the token until the auction is settled
+    ICEP47::new(collection).transfer_from(Key::from(offerer), Key::from(me), vec![token_id]);
+
+    let token_owner = ICEP47::new(collection).owner_of(token_id);
+
+    if token_owner != Some(Key::from(me)) {
+        runtime::revert(Error::NotOwner);
+    }
+
+    let bids: Vec<Bid> = Vec::new();
+    let sealed_bids: Vec<SealedBid> = Vec::new();
+    let status = AuctionStatus::Active;
     let auction = Auction {
         offerer,
         collection,
         token_id,
         auction_type,
         price,
+        floor_price,
         start_time,
         end_time,
+        reveal_end_time,
+        second_price,
+        extension_window,
+        extension_duration,
+        min_bid_increment,
+        min_bid_increment_rate,
         bids,
+        sealed_bids,
+        status,
+    };
+    store_result(auction.clone());
+    auctions::write_auction(auction);
+    on_auction::insert(collection, token_id, offerer, end_time.unwrap_or_revert());
+    event::emit(&Event::AuctionCreated {
+        offerer,
+        collection: collection.into(),
+        token_id,
+        auction_type,
+        start_time,
+        end_time,
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn place_bid() {
+    let _ = purse::checked_balance();
+    let collection: ContractHash = {
+        let collection_key: Key = runtime::get_named_arg(COLLECTION_RUNTIME_ARG_NAME);
+        ContractHash::new(collection_key.into_hash().unwrap())
+    };
+    let token_id: U256 = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let deposit: U512 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let bidder = runtime::get_caller();
+    let bid_time = u64::from(runtime::get_blocktime());
+
+    let mut auction = auctions::read_auction(collection, token_id)
+        .filter(|auction| auction.status == AuctionStatus::Active)
+        .unwrap_or_revert_with(Error::AuctionNotExist);
+
+    // The highest bidder raises their bid by depositing only the difference
+    let price = match auction.get_highest_bid_index() {
+        Some(index) if auction.bids[index].offerer == bidder => auction.bids[index]
+            .price
+            .checked_add(deposit)
+            .unwrap_or_revert(),
+        _ => deposit,
+    };
+
+    if bidder == auction.offerer {
+        runtime::revert(Error::PermissionDenied);
+    }
+    if bid_time < auction.start_time {
+        runtime::revert(Error::AuctionNotStarted);
+    }
+    let end_time = auction.end_time.unwrap_or_revert();
+    if bid_time >= end_time {
+        runtime::revert(Error::AuctionEnded);
+    }
+
+    match (auction.auction_type, auction.price) {
+        (AuctionType::Decreasing, _) => {
+            // The first bid at the current price wins immediately
+            let current_price = auction.get_decreasing_price(bid_time).unwrap_or_revert();
+            if price < current_price {
+                runtime::revert(Error::BidTooLow);
+            }
+            buy_auction_now(auction, bidder, price, current_price, bid_time);
+            return;
+        }
+        (AuctionType::FixedPrice, Some(buy_now_price)) if price >= buy_now_price => {
+            buy_auction_now(auction, bidder, price, buy_now_price, bid_time);
+            return;
+        }
+        (AuctionType::Sealed, _) => runtime::revert(Error::InvalidAuctionType),
+        _ => {}
+    }
+
+    if let Some(index) = auction.get_highest_bid_index() {
+        let min_next_bid = auction
+            .get_min_next_bid(auction.bids[index].price)
+            .unwrap_or_revert();
+        let highest_bid = auction.bids.get_mut(index).unwrap();
+        if price <= highest_bid.price {
+            runtime::revert(Error::BidTooLow);
+        }
+        if price < min_next_bid {
+            runtime::revert(Error::BidIncrementTooLow);
+        }
+        if highest_bid.offerer == bidder {
+            // The escrowed bid is carried over into the raised one
+            highest_bid.status = BidStatus::Canceled;
+        } else {
+            // Refund the outbid bidder
+            purse::transfer(highest_bid.offerer, highest_bid.price);
+            highest_bid.status = BidStatus::NotAccepted;
+        }
+    } else if price.is_zero() {
+        runtime::revert(Error::BidTooLow);
+    }
+
+    auction.bids.push(Bid {
+        offerer: bidder,
+        price,
+        bid_time,
+        status: BidStatus::Pending,
+        expires_at: None,
+    });
+
+    // Push the deadline back when the bid lands in the anti-sniping window
+    let extended = end_time - bid_time <= auction.extension_window;
+    if extended {
+        auction.end_time = Some(
+            end_time
+                .checked_add(auction.extension_duration)
+                .unwrap_or_revert(),
+        );
+    }
+
+    store_result(auction.clone());
+    auctions::write_auction(auction.clone());
+    event::emit(&Event::BidPlaced {
+        bidder,
+        collection: collection.into(),
+        token_id,
+        price,
+    });
+    if extended {
+        let end_time = auction.end_time.unwrap_or_revert();
+        on_auction::update_end_time(collection, token_id, end_time);
+        event::emit(&Event::AuctionExtended {
+            collection: collection.into(),
+            token_id,
+            end_time,
+        });
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn commit_bid() {
+    let _ = purse::checked_balance();
+    let collection: ContractHash = {
+        let collection_key: Key = runtime::get_named_arg(COLLECTION_RUNTIME_ARG_NAME);
+        ContractHash::new(collection_key.into_hash().unwrap())
+    };
+    let token_id: U256 = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let commitment: [u8; 32] = runtime::get_named_arg(COMMITMENT_RUNTIME_ARG_NAME);
+    let deposit: U512 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let bidder = runtime::get_caller();
+    let bid_time = u64::from(runtime::get_blocktime());
+
+    let mut auction = auctions::read_auction(collection, token_id)
+        .filter(|auction| auction.status == AuctionStatus::Active)
+        .unwrap_or_revert_with(Error::AuctionNotExist);
+
+    if auction.auction_type != AuctionType::Sealed {
+        runtime::revert(Error::InvalidAuctionType);
+    }
+    if bidder == auction.offerer {
+        runtime::revert(Error::PermissionDenied);
+    }
+    if bid_time < auction.start_time {
+        runtime::revert(Error::AuctionNotStarted);
+    }
+    if bid_time >= auction.end_time.unwrap_or_revert() {
+        runtime::revert(Error::AuctionEnded);
+    }
+    if auction.get_sealed_bid_index_by_account(bidder).is_some() {
+        runtime::revert(Error::BidExist);
+    }
+    // Reveals are capped by the deposit, so it must at least cover the reserve
+    let minimum = auction
+        .price
+        .unwrap_or_default()
+        .max(auction.floor_price.unwrap_or_default());
+    if deposit.is_zero() || deposit < minimum {
+        runtime::revert(Error::NotValidAmount);
+    }
+
+    auction.sealed_bids.push(SealedBid {
+        offerer: bidder,
+        commitment,
+        deposit,
+        price: None,
+        bid_time,
+        status: BidStatus::Pending,
+    });
+
+    store_result(auction.clone());
+    auctions::write_auction(auction);
+    event::emit(&Event::BidCommitted {
+        bidder,
+        collection: collection.into(),
+        token_id,
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn reveal_bid() {
+    let collection: ContractHash = {
+        let collection_key: Key = runtime::get_named_arg(COLLECTION_RUNTIME_ARG_NAME);
+        ContractHash::new(collection_key.into_hash().unwrap())
+    };
+    let token_id: U256 = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let price: U512 = runtime::get_named_arg(PRICE_RUNTIME_ARG_NAME);
+    let salt: String = runtime::get_named_arg(SALT_RUNTIME_ARG_NAME);
+    let bidder = runtime::get_caller();
+    let now = u64::from(runtime::get_blocktime());
+
+    let mut auction = auctions::read_auction(collection, token_id)
+        .filter(|auction| auction.status == AuctionStatus::Active)
+        .unwrap_or_revert_with(Error::AuctionNotExist);
+
+    if now < auction.end_time.unwrap_or_revert() {
+        runtime::revert(Error::AuctionNotEnded);
+    }
+    if now
+        >= auction
+            .reveal_end_time
+            .unwrap_or_revert_with(Error::InvalidAuctionType)
+    {
+        runtime::revert(Error::AuctionEnded);
+    }
+
+    let index = auction
+        .get_sealed_bid_index_by_account(bidder)
+        .unwrap_or_revert_with(Error::InvalidReveal);
+    let sealed_bid = auction.sealed_bids.get_mut(index).unwrap();
+    if sealed_bid.price.is_some()
+        || sealed_bid.commitment != auctions::make_commitment(bidder, price, &salt)
+        || price > sealed_bid.deposit
+    {
+        runtime::revert(Error::InvalidReveal);
+    }
+    sealed_bid.price = Some(price);
+
+    store_result(auction.clone());
+    auctions::write_auction(auction);
+    event::emit(&Event::BidRevealed {
+        bidder,
+        collection: collection.into(),
+        token_id,
+        price,
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn settle_auction() {
+    let collection: ContractHash = {
+        let collection_key: Key = runtime::get_named_arg(COLLECTION_RUNTIME_ARG_NAME);
+        ContractHash::new(collection_key.into_hash().unwrap())
+    };
+    let token_id: U256 = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+
+    let auction = auctions::read_auction(collection, token_id)
+        .filter(|auction| auction.status == AuctionStatus::Active)
+        .unwrap_or_revert_with(Error::AuctionNotExist);
+
+    let now = u64::from(runtime::get_blocktime());
+    if let AuctionType::Sealed = auction.auction_type {
+        if now < auction.reveal_end_time.unwrap_or_revert() {
+            runtime::revert(Error::AuctionNotEnded);
+        }
+        settle_sealed_auction(auction);
+        return;
+    }
+    if now < auction.end_time.unwrap_or_revert() {
+        runtime::revert(Error::AuctionNotEnded);
+    }
+
+    let index = auction.get_highest_bid_index();
+    let reserve_met = match (auction.auction_type, auction.price, index) {
+        (AuctionType::Basic, Some(reserve), Some(index)) => auction.bids[index].price >= reserve,
+        _ => true,
+    };
+    if reserve_met {
+        finish_auction(auction, index);
+    } else {
+        finish_auction(auction, None);
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn cancel_auction() {
+    let collection: ContractHash = {
+        let collection_key: Key = runtime::get_named_arg(COLLECTION_RUNTIME_ARG_NAME);
+        ContractHash::new(collection_key.into_hash().unwrap())
+    };
+    let token_id: U256 = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+
+    let auction = auctions::read_auction(collection, token_id)
+        .filter(|auction| auction.status == AuctionStatus::Active)
+        .unwrap_or_revert_with(Error::AuctionNotExist);
+
+    if runtime::get_caller() != auction.offerer {
+        runtime::revert(Error::PermissionDenied);
+    }
+    // The seller can only back out before anyone has bid
+    let now = u64::from(runtime::get_blocktime());
+    if now >= auction.start_time && !(auction.bids.is_empty() && auction.sealed_bids.is_empty()) {
+        runtime::revert(Error::PermissionDenied);
+    }
+    close_canceled_auction(auction);
+}
+
+#[no_mangle]
+pub extern "C" fn force_cancel_auction() {
+    let collection: ContractHash = {
+        let collection_key: Key = runtime::get_named_arg(COLLECTION_RUNTIME_ARG_NAME);
+        ContractHash::new(collection_key.into_hash().unwrap())
+    };
+    let token_id: U256 = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+
+    let auction = auctions::read_auction(collection, token_id)
+        .filter(|auction| auction.status == AuctionStatus::Active)
+        .unwrap_or_revert_with(Error::AuctionNotExist);
+
+    close_canceled_auction(auction);
+}
+
+#[no_mangle]
+pub extern "C" fn get_auction_price() {
+    let collection: ContractHash = {
+        let collection_key: Key = runtime::get_named_arg(COLLECTION_RUNTIME_ARG_NAME);
+        ContractHash::new(collection_key.into_hash().unwrap())
+    };
+    let token_id: U256 = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+
+    let auction = auctions::read_auction(collection, token_id)
+        .filter(|auction| auction.status == AuctionStatus::Active)
+        .unwrap_or_revert_with(Error::AuctionNotExist);
+
+    let now = u64::from(runtime::get_blocktime());
+    let price: U512 = match auction.auction_type {
+        AuctionType::Decreasing => auction.get_decreasing_price(now).unwrap_or_revert(),
+        _ => auction
+            .get_highest_bid_index()
+            .map(|index| auction.bids[index].price)
+            .unwrap_or_default(),
+    };
+    runtime::ret(CLValue::from_t(price).unwrap_or_revert());
+}
+
+#[no_mangle]
+pub extern "C" fn get_auctions_by_collection() {
+    let collection: ContractHash = {
+        let collection_key: Key = runtime::get_named_arg(COLLECTION_RUNTIME_ARG_NAME);
+        ContractHash::new(collection_key.into_hash().unwrap())
+    };
+    let offset: u64 = runtime::get_named_arg(OFFSET_RUNTIME_ARG_NAME);
+    let limit: u64 = runtime::get_named_arg(LIMIT_RUNTIME_ARG_NAME);
+
+    let on_auctions: Vec<OnAuction> = on_auction::read_on_auctions()
+        .into_iter()
+        .filter(|on_auction| on_auction.0 == collection)
+        .collect();
+    ret_auctions(on_auctions, offset, limit);
+}
+
+#[no_mangle]
+pub extern "C" fn get_auctions_by_seller() {
+    let seller: AccountHash = {
+        let seller_key: Key = runtime::get_named_arg(SELLER_RUNTIME_ARG_NAME);
+        seller_key.into_account().unwrap_or_revert()
+    };
+    let offset: u64 = runtime::get_named_arg(OFFSET_RUNTIME_ARG_NAME);
+    let limit: u64 = runtime::get_named_arg(LIMIT_RUNTIME_ARG_NAME);
+
+    let on_auctions: Vec<OnAuction> = on_auction::read_on_auctions()
+        .into_iter()
+        .filter(|on_auction| (on_auction.2).0 == seller)
+        .collect();
+    ret_auctions(on_auctions, offset, limit);
+}
+
+#[no_mangle]
+pub extern "C" fn get_auctions_by_end_time() {
+    let offset: u64 = runtime::get_named_arg(OFFSET_RUNTIME_ARG_NAME);
+    let limit: u64 = runtime::get_named_arg(LIMIT_RUNTIME_ARG_NAME);
+
+    // Auctions ending soonest come first
+    let mut on_auctions: Vec<OnAuction> = on_auction::read_on_auctions();
+    on_auctions.sort_by_key(|on_auction| (on_auction.2).1);
+    ret_auctions(on_auctions, offset, limit);
+}
+
+/// Returns the page of `on_auctions` starting at `offset` with at most `limit` auctions.
+fn ret_auctions(on_auctions: Vec<OnAuction>, offset: u64, limit: u64) {
+    let auctions: Vec<Auction> = on_auctions
+        .into_iter()
+        .skip(offset as usize)
+        .take(limit as usize)
+        .filter_map(|on_auction| auctions::read_auction(on_auction.0, on_auction.1))
+        .collect();
+    runtime::ret(CLValue::from_t(auctions).unwrap_or_revert());
+}
+
+/// Groups `tokens` by collection so each collection is called once.
+fn group_by_collection(tokens: &[(ContractHash, U256)]) -> BTreeMap<ContractHash, Vec<U256>> {
+    let mut tokens_by_collection: BTreeMap<ContractHash, Vec<U256>> = BTreeMap::new();
+    for (collection, token_id) in tokens.iter() {
+        tokens_by_collection
+            .entry(*collection)
+            .or_insert_with(Vec::new)
+            .push(*token_id);
+    }
+    tokens_by_collection
+}
+
+/// Whether the seller of a non-custodial listing still owns the token and approves the
+/// marketplace. Custodial listings are always backed by the escrowed token.
+fn is_order_backed(order: &Order) -> bool {
+    if order.custodial {
+        return true;
+    }
+    let me = detail::get_caller_address()
+        .unwrap()
+        .as_contract_package_hash()
+        .unwrap()
+        .clone();
+    let token_owner = ICEP47::new(order.collection).owner_of(order.token_id);
+    let approved =
+        ICEP47::new(order.collection).get_approved(Key::from(order.offerer), order.token_id);
+    token_owner == Some(Key::from(order.offerer)) && approved == Some(Key::from(me))
+}
+
+/// Marks a listing that is no longer backed as invalid and drops it from `on_orders`.
+fn invalidate_order(mut order: Order) {
+    order.is_active = false;
+    order.is_valid = false;
+
+    let find_result = on_orders::find(order.collection, order.token_id);
+    let mut on_orders: Vec<OnOrder> = on_orders::read_on_orders();
+    on_orders.remove(find_result.unwrap_or_revert());
+    on_orders::write_on_orders(on_orders);
+    orders::write_order(order);
+    store_result(order);
+    event::emit(&Event::OrderInvalidated {
+        order_id: order.order_id,
+        offerer: order.offerer,
+        collection: order.collection.into(),
+        token_id: order.token_id,
+    });
+}
+
+/// Hands the token of `order` to `buyer` and its price to the seller and treasury wallet.
+fn sell_order(mut order: Order, buyer: AccountHash, refund: U512) {
+    order.is_active = false;
+    order.buyer = Some(buyer);
+    order.sold_at = Some(u64::from(runtime::get_blocktime()));
+
+    // Close the order before any external call so a re-entrant buy finds it sold
+    let find_result = on_orders::find(order.collection, order.token_id);
+    let mut on_orders: Vec<OnOrder> = on_orders::read_on_orders();
+    on_orders.remove(find_result.unwrap_or_revert());
+    on_orders::write_on_orders(on_orders);
+    orders::write_order(order);
+    store_result(order);
+
+    // Send NFT to buyer
+    if order.custodial {
+        ICEP47::new(order.collection).transfer(Key::from(buyer), vec![order.token_id]);
+    } else {
+        ICEP47::new(order.collection).transfer_from(
+            Key::from(order.offerer),
+            Key::from(buyer),
+            vec![order.token_id],
+        );
+    }
+    // Send CSPR to order offerer and treasury wallet
+    purse::transfer_with_fee(order.offerer, order.price);
+    event::emit(&Event::OrderBought {
+        order_id: order.order_id,
+        seller: order.offerer,
+        buyer,
+        collection: order.collection.into(),
+        token_id: order.token_id,
+        price: order.price,
+        refund,
+    });
+}
+
+/// Ends an auction at once in favour of `buyer` at `price`, refunding the escrowed bid and any
+/// overpayment of the `amount` held for the buyer, their own escrowed bid included.
+fn buy_auction_now(mut auction: Auction, buyer: AccountHash, amount: U512, price: U512, time: u64) {
+    let mut refunds: Vec<(AccountHash, U512)> = Vec::new();
+    if let Some(index) = auction.get_highest_bid_index() {
+        let bid = auction.bids.get_mut(index).unwrap();
+        if bid.offerer == buyer {
+            bid.status = BidStatus::Canceled;
+        } else {
+            refunds.push((bid.offerer, bid.price));
+            bid.status = BidStatus::NotAccepted;
+        }
+    }
+    if amount > price {
+        refunds.push((buyer, amount.checked_sub(price).unwrap_or_revert()));
+    }
+    auction.bids.push(Bid {
+        offerer: buyer,
+        price,
+        bid_time: time,
+        status: BidStatus::Pending,
+        expires_at: None,
+    });
+    let index = auction.bids.len() - 1;
+    finish_auction(auction, Some(index));
+
+    for (account, refund) in refunds {
+        purse::transfer(account, refund);
+    }
+}
+
+/// Refunds every escrowed bid and deposit of a canceled auction and returns the token to the
+/// seller.
+fn close_canceled_auction(mut auction: Auction) {
+    let mut refunds: Vec<(AccountHash, U512)> = Vec::new();
+    for bid in auction.bids.iter_mut() {
+        if bid.status == BidStatus::Pending {
+            refunds.push((bid.offerer, bid.price));
+            bid.status = BidStatus::Canceled;
+        }
+    }
+    for sealed_bid in auction.sealed_bids.iter_mut() {
+        if sealed_bid.status == BidStatus::Pending {
+            refunds.push((sealed_bid.offerer, sealed_bid.deposit));
+            sealed_bid.status = BidStatus::Canceled;
+        }
+    }
+    auction.status = AuctionStatus::Canceled;
+
+    // Close the auction before any external call so a re-entrant call finds it closed
+    store_result(auction.clone());
+    auctions::write_auction(auction.clone());
+    on_auction::remove(auction.collection, auction.token_id);
+
+    for (account, refund) in refunds {
+        purse::transfer(account, refund);
+    }
+    ICEP47::new(auction.collection).transfer(Key::from(auction.offerer), vec![auction.token_id]);
+    event::emit(&Event::AuctionCanceled {
+        offerer: auction.offerer,
+        collection: auction.collection.into(),
+        token_id: auction.token_id,
+    });
+}
+
+/// Closes a sealed auction in favour of the highest revealed bid, ties going to the earliest
+/// commitment. The winner pays their own bid, or the second highest revealed bid (at least the
+/// reserve) under second-price rules. Every other deposit is refunded in full.
+fn settle_sealed_auction(mut auction: Auction) {
+    let mut winner: Option<usize> = None;
+    let mut second_highest: Option<U512> = None;
+    for (index, sealed_bid) in auction.sealed_bids.iter().enumerate() {
+        let price = match sealed_bid.price {
+            Some(price) => price,
+            None => continue,
+        };
+        match winner.and_then(|winner| auction.sealed_bids[winner].price) {
+            Some(highest) if price <= highest => {
+                if second_highest.map_or(true, |second| price > second) {
+                    second_highest = Some(price);
+                }
+            }
+            highest => {
+                second_highest = highest;
+                winner = Some(index);
+            }
+        }
+    }
+
+    let reserve = auction.price.unwrap_or_default();
+    let winner = winner.filter(|&index| auction.sealed_bids[index].price.unwrap() >= reserve);
+    let payment = winner.map(|index| {
+        if auction.second_price {
+            second_highest.unwrap_or_default().max(reserve)
+        } else {
+            auction.sealed_bids[index].price.unwrap()
+        }
+    });
+
+    let mut refunds: Vec<(AccountHash, U512)> = Vec::new();
+    for (index, sealed_bid) in auction.sealed_bids.iter_mut().enumerate() {
+        let refund = if Some(index) == winner {
+            sealed_bid.status = BidStatus::Accepted;
+            sealed_bid
+                .deposit
+                .checked_sub(payment.unwrap())
+                .unwrap_or_revert()
+        } else {
+            sealed_bid.status = BidStatus::NotAccepted;
+            sealed_bid.deposit
+        };
+        if !refund.is_zero() {
+            refunds.push((sealed_bid.offerer, refund));
+        }
+    }
+
+    match (winner, payment) {
+        (Some(index), Some(payment)) => {
+            let sealed_bid = auction.sealed_bids[index];
+            auction.bids.push(Bid {
+                offerer: sealed_bid.offerer,
+                price: payment,
+                bid_time: sealed_bid.bid_time,
+                status: BidStatus::Pending,
+                expires_at: None,
+            });
+            let index = auction.bids.len() - 1;
+            finish_auction(auction, Some(index));
+        }
+        _ => finish_auction(auction, None),
+    }
+
+    for (account, refund) in refunds {
+        purse::transfer(account, refund);
+    }
+}
+
+/// Closes an auction, handing the token to the bid at `winning_index` and its price to the
+/// seller. Without a winner the token goes back to the seller and any escrowed bid is refunded.
+fn finish_auction(mut auction: Auction, winning_index: Option<usize>) {
+    let collection = auction.collection;
+    let token_id = auction.token_id;
+    let (bidder, price, refund) = match winning_index {
+        Some(index) => {
+            let winning_bid = auction.bids.get_mut(index).unwrap();
+            winning_bid.status = BidStatus::Accepted;
+            auction.status = AuctionStatus::Sold;
+            (Some(winning_bid.offerer), winning_bid.price, None)
+        }
+        None => {
+            let refund = auction.get_highest_bid_index().map(|index| {
+                let bid = auction.bids.get_mut(index).unwrap();
+                bid.status = BidStatus::NotAccepted;
+                (bid.offerer, bid.price)
+            });
+            auction.status = AuctionStatus::Unsold;
+            (None, U512::zero(), refund)
+        }
     };
-    store_result(auction);
+
+    // Close the auction before any external call so a re-entrant settle finds it closed
+    store_result(auction.clone());
+    auctions::write_auction(auction.clone());
+    on_auction::remove(collection, token_id);
+
+    match bidder {
+        Some(bidder) => {
+            // Send NFT to the winner and CSPR to the seller and treasury wallet
+            ICEP47::new(collection).transfer(Key::from(bidder), vec![token_id]);
+            purse::transfer_with_fee(auction.offerer, price);
+        }
+        None => {
+            // Refund the remaining bid and return the token to the seller
+            if let Some((offerer, refund)) = refund {
+                purse::transfer(offerer, refund);
+            }
+            ICEP47::new(collection).transfer(Key::from(auction.offerer), vec![token_id]);
+        }
+    }
+    event::emit(&Event::AuctionSettled {
+        offerer: auction.offerer,
+        bidder,
+        collection: collection.into(),
+        token_id,
+        price,
+        status: auction.status,
+    });
 }
 
 #[no_mangle]
@@ -423,11 +1846,35 @@ pub extern "C" fn call() {
         Key::from(fee_uref)
     };
 
+    let anti_sniping_key: Key = {
+        // Bids in the last 10 minutes extend the auction by 10 minutes
+        let anti_sniping: AntiSniping = (600_000u64, 600_000u64);
+        let anti_sniping_uref: URef = storage::new_uref(anti_sniping).into_read_write();
+        Key::from(anti_sniping_uref)
+    };
+
+    let min_bid_increment_key: Key = {
+        // Bids must beat the highest bid by at least 1%
+        let min_bid_increment: MinBidIncrement = (U512::zero(), U512::from(10u8));
+        let min_bid_increment_uref: URef = storage::new_uref(min_bid_increment).into_read_write();
+        Key::from(min_bid_increment_uref)
+    };
+
     let orders_key: Key = {
         let uref = storage::new_dictionary(ORDERS_KEY_NAME).unwrap();
         Key::from(uref)
     };
 
+    let order_ids_key: Key = {
+        let uref = storage::new_dictionary(ORDER_IDS_KEY_NAME).unwrap();
+        Key::from(uref)
+    };
+
+    let order_count_key: Key = {
+        let uref: URef = storage::new_uref(0u64).into_read_write();
+        Key::from(uref)
+    };
+
     let on_orders_key: Key = {
         let init_value: Vec<OnOrder> = Vec::new();
         let uref: URef = storage::new_uref(init_value).into_read_write();
@@ -440,15 +1887,65 @@ pub extern "C" fn call() {
         Key::from(uref)
     };
 
+    let auctions_key: Key = {
+        let uref = storage::new_dictionary(AUCTIONS_KEY_NAME).unwrap();
+        Key::from(uref)
+    };
+
+    let bundles_key: Key = {
+        let uref = storage::new_dictionary(BUNDLES_KEY_NAME).unwrap();
+        Key::from(uref)
+    };
+
+    let bundle_count_key: Key = {
+        let uref: URef = storage::new_uref(0u64).into_read_write();
+        Key::from(uref)
+    };
+
+    let collection_offers_key: Key = {
+        let uref = storage::new_dictionary(COLLECTION_OFFERS_KEY_NAME).unwrap();
+        Key::from(uref)
+    };
+
+    let collection_offer_count_key: Key = {
+        let uref: URef = storage::new_uref(0u64).into_read_write();
+        Key::from(uref)
+    };
+
+    let on_auctions_key: Key = {
+        let init_value: Vec<OnAuction> = Vec::new();
+        let uref: URef = storage::new_uref(init_value).into_read_write();
+        Key::from(uref)
+    };
+
     let admins: Vec<AccountHash> = runtime::get_named_arg(ADMINS_RUNTIME_ARG_NAME);
 
     let mut named_keys = NamedKeys::new();
 
     named_keys.insert(ORDERS_KEY_NAME.to_string(), orders_key);
+    named_keys.insert(ORDER_IDS_KEY_NAME.to_string(), order_ids_key);
+    named_keys.insert(ORDER_COUNT_KEY_NAME.to_string(), order_count_key);
     named_keys.insert(TREASURY_WALLET_KEY_NAME.to_string(), treasury_wallet_key);
     named_keys.insert(FEE_KEY_NAME.to_string(), fee_key);
+    named_keys.insert(ANTI_SNIPING_KEY_NAME.to_string(), anti_sniping_key);
+    named_keys.insert(
+        MIN_BID_INCREMENT_KEY_NAME.to_string(),
+        min_bid_increment_key,
+    );
     named_keys.insert(ON_ORDERS_KEY_NAME.to_string(), on_orders_key);
     named_keys.insert(ON_OFFERS_KEY_NAME.to_string(), on_offers_key);
+    named_keys.insert(AUCTIONS_KEY_NAME.to_string(), auctions_key);
+    named_keys.insert(ON_AUCTIONS_KEY_NAME.to_string(), on_auctions_key);
+    named_keys.insert(BUNDLES_KEY_NAME.to_string(), bundles_key);
+    named_keys.insert(BUNDLE_COUNT_KEY_NAME.to_string(), bundle_count_key);
+    named_keys.insert(
+        COLLECTION_OFFERS_KEY_NAME.to_string(),
+        collection_offers_key,
+    );
+    named_keys.insert(
+        COLLECTION_OFFER_COUNT_KEY_NAME.to_string(),
+        collection_offer_count_key,
+    );
 
     let mut admin_group = storage::create_contract_user_group(
         contract_package_hash,
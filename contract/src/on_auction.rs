@@ -1,3 +1,55 @@
-use alloc::collections::BTreeMap;
+//! Implementation of on_auctions.
 
-// pub type OnAuction = BTreeMap<>
+use alloc::vec::Vec;
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{account::AccountHash, ContractHash, URef, U256};
+
+use crate::{constants::ON_AUCTIONS_KEY_NAME, detail};
+
+/// Active auction keyed by collection and token id, with its seller and end time.
+pub type OnAuction = (ContractHash, U256, (AccountHash, u64));
+
+#[inline]
+pub(crate) fn on_auctions_uref() -> URef {
+    detail::get_uref(ON_AUCTIONS_KEY_NAME)
+}
+
+pub(crate) fn read_on_auctions() -> Vec<OnAuction> {
+    let uref = on_auctions_uref();
+    storage::read(uref).unwrap_or_revert().unwrap_or_default()
+}
+
+pub(crate) fn write_on_auctions(value: Vec<OnAuction>) {
+    let uref = on_auctions_uref();
+    storage::write(uref, value);
+}
+
+pub(crate) fn find(collection: ContractHash, token_id: U256) -> Option<usize> {
+    let on_auctions = read_on_auctions();
+    let result = on_auctions
+        .iter()
+        .position(|on_auction| on_auction.0.eq(&collection) && on_auction.1.eq(&token_id));
+    result
+}
+
+pub(crate) fn insert(collection: ContractHash, token_id: U256, seller: AccountHash, end_time: u64) {
+    let mut on_auctions = read_on_auctions();
+    on_auctions.push((collection, token_id, (seller, end_time)));
+    write_on_auctions(on_auctions);
+}
+
+pub(crate) fn update_end_time(collection: ContractHash, token_id: U256, end_time: u64) {
+    if let Some(index) = find(collection, token_id) {
+        let mut on_auctions = read_on_auctions();
+        (on_auctions[index].2).1 = end_time;
+        write_on_auctions(on_auctions);
+    }
+}
+
+pub(crate) fn remove(collection: ContractHash, token_id: U256) {
+    if let Some(index) = find(collection, token_id) {
+        let mut on_auctions = read_on_auctions();
+        on_auctions.remove(index);
+        write_on_auctions(on_auctions);
+    }
+}
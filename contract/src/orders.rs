@@ -1,5 +1,8 @@
-//! Implementation of allowances.
-use alloc::{string::String, vec::Vec};
+//! Implementation of orders.
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use casper_contract::{
     contract_api::{runtime, storage},
@@ -7,14 +10,27 @@ use casper_contract::{
 };
 use casper_types::{bytesrepr::ToBytes, ContractHash, Key, URef, U256};
 
-use crate::{constants::ORDERS_KEY_NAME, detail, Order};
+use crate::{
+    constants::{ORDERS_KEY_NAME, ORDER_COUNT_KEY_NAME, ORDER_IDS_KEY_NAME},
+    detail, Order,
+};
 
 #[inline]
 pub(crate) fn orders_uref() -> URef {
     detail::get_uref(ORDERS_KEY_NAME)
 }
 
-/// Creates a dictionary item key for an (owner, spender) pair.
+#[inline]
+pub(crate) fn order_ids_uref() -> URef {
+    detail::get_uref(ORDER_IDS_KEY_NAME)
+}
+
+#[inline]
+pub(crate) fn order_count_uref() -> URef {
+    detail::get_uref(ORDER_COUNT_KEY_NAME)
+}
+
+/// Creates a dictionary item key for a (collection, token_id) pair.
 fn make_dictionary_item_key(collection: ContractHash, token_id: U256) -> String {
     let mut preimage = Vec::new();
     preimage.append(&mut Key::from(collection).to_bytes().unwrap_or_revert());
@@ -24,18 +40,31 @@ fn make_dictionary_item_key(collection: ContractHash, token_id: U256) -> String
     hex::encode(&key_bytes)
 }
 
-/// Writes an allowance for owner and spender for a specific amount.
+/// Returns a fresh order id and bumps the order count.
+pub(crate) fn next_order_id() -> u64 {
+    let uref = order_count_uref();
+    let order_id: u64 = storage::read(uref).unwrap_or_revert().unwrap_or_default();
+    storage::write(uref, order_id.checked_add(1).unwrap_or_revert());
+    order_id
+}
+
+/// Writes an order under its id and points its token at it.
 pub(crate) fn write_order(order: Order) {
     let dictionary_item_key = make_dictionary_item_key(order.collection, order.token_id);
-    let orders_uref = orders_uref();
-    storage::dictionary_put(orders_uref, &dictionary_item_key, order);
+    storage::dictionary_put(order_ids_uref(), &dictionary_item_key, order.order_id);
+    storage::dictionary_put(orders_uref(), &order.order_id.to_string(), order);
 }
 
-/// Reads an allowance for a owner and spender
+/// Reads the order with `order_id`, if any.
+pub(crate) fn read_order_by_id(order_id: u64) -> Option<Order> {
+    storage::dictionary_get(orders_uref(), &order_id.to_string()).unwrap_or_revert()
+}
+
+/// Reads the current order of a token.
 pub(crate) fn read_order(collection: ContractHash, token_id: U256) -> Order {
     let dictionary_item_key = make_dictionary_item_key(collection, token_id);
-    let orders_uref = orders_uref();
-    storage::dictionary_get(orders_uref, &dictionary_item_key)
-        .unwrap_or_revert()
+    let order_id: u64 = storage::dictionary_get(order_ids_uref(), &dictionary_item_key)
         .unwrap_or_revert()
+        .unwrap_or_revert();
+    read_order_by_id(order_id).unwrap_or_revert()
 }
@@ -14,12 +14,14 @@ const GET_PURSE_ENTRY_NAME: &str = "get_purse";
 const AMOUNT_RUNTIME_ARG_NAME: &str = "amount";
 const COLLECTION_RUNTIME_ARG_NAME: &str = "collection";
 const TOKEN_ID_RUNTIME_ARG_NAME: &str = "token_id";
+const EXPECTED_PRICE_RUNTIME_ARG_NAME: &str = "expected_price";
 
 #[no_mangle]
 fn call() {
     let amount: U512 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
     let collection_key: Key = runtime::get_named_arg(COLLECTION_RUNTIME_ARG_NAME);
     let token_id: U256 = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let expected_price: U512 = runtime::get_named_arg(EXPECTED_PRICE_RUNTIME_ARG_NAME);
 
     let marketplace_contract_hash: ContractHash = {
         let ido_contract_hash_key: Key = runtime::get_named_arg(MARKETPLACE_CONTRACT_HASH_ARG_NAME);
@@ -47,6 +49,7 @@ fn call() {
             COLLECTION_RUNTIME_ARG_NAME => collection_key,
             TOKEN_ID_RUNTIME_ARG_NAME => token_id,
             AMOUNT_RUNTIME_ARG_NAME => amount,
+            EXPECTED_PRICE_RUNTIME_ARG_NAME => expected_price,
         },
     );
 }
@@ -0,0 +1,62 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::{collections::BTreeMap, string::String};
+
+use casper_contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+
+use casper_types::{runtime_args, ContractHash, Key, RuntimeArgs, URef, U512};
+
+const MARKETPLACE_CONTRACT_HASH_ARG_NAME: &str = "marketplace_contract_hash";
+const CREATE_COLLECTION_OFFER_ENTRY_NAME: &str = "create_collection_offer";
+const GET_PURSE_ENTRY_NAME: &str = "get_purse";
+const COLLECTION_RUNTIME_ARG_NAME: &str = "collection";
+const AMOUNT_RUNTIME_ARG_NAME: &str = "amount";
+const PRICE_RUNTIME_ARG_NAME: &str = "price";
+const QUANTITY_RUNTIME_ARG_NAME: &str = "quantity";
+const TRAITS_RUNTIME_ARG_NAME: &str = "traits";
+
+#[no_mangle]
+fn call() {
+    let amount: U512 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
+    let collection_key: Key = runtime::get_named_arg(COLLECTION_RUNTIME_ARG_NAME);
+    let price: U512 = runtime::get_named_arg(PRICE_RUNTIME_ARG_NAME);
+    let quantity: u64 = runtime::get_named_arg(QUANTITY_RUNTIME_ARG_NAME);
+    let traits: BTreeMap<String, String> = runtime::get_named_arg(TRAITS_RUNTIME_ARG_NAME);
+
+    let marketplace_contract_hash: ContractHash = {
+        let ido_contract_hash_key: Key = runtime::get_named_arg(MARKETPLACE_CONTRACT_HASH_ARG_NAME);
+        ido_contract_hash_key
+            .into_hash()
+            .map(ContractHash::new)
+            .unwrap()
+    };
+
+    let sender_purse: URef = account::get_main_purse();
+
+    let deposit_purse: URef = runtime::call_contract(
+        marketplace_contract_hash,
+        GET_PURSE_ENTRY_NAME,
+        runtime_args! {},
+    );
+
+    system::transfer_from_purse_to_purse(sender_purse, deposit_purse, amount, None)
+        .unwrap_or_revert();
+
+    runtime::call_contract::<()>(
+        marketplace_contract_hash,
+        CREATE_COLLECTION_OFFER_ENTRY_NAME,
+        runtime_args! {
+            COLLECTION_RUNTIME_ARG_NAME => collection_key,
+            PRICE_RUNTIME_ARG_NAME => price,
+            QUANTITY_RUNTIME_ARG_NAME => quantity,
+            TRAITS_RUNTIME_ARG_NAME => traits,
+            AMOUNT_RUNTIME_ARG_NAME => amount
+        },
+    );
+}
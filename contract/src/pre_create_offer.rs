@@ -14,12 +14,14 @@ const GET_PURSE_ENTRY_NAME: &str = "get_purse";
 const COLLECTION_RUNTIME_ARG_NAME: &str = "collection";
 const AMOUNT_RUNTIME_ARG_NAME: &str = "amount";
 const TOKEN_ID_RUNTIME_ARG_NAME: &str = "token_id";
+const EXPIRES_AT_RUNTIME_ARG_NAME: &str = "expires_at";
 
 #[no_mangle]
 fn call() {
     let amount: U512 = runtime::get_named_arg(AMOUNT_RUNTIME_ARG_NAME);
     let collection_key: Key = runtime::get_named_arg(COLLECTION_RUNTIME_ARG_NAME);
     let token_id: U256 = runtime::get_named_arg(TOKEN_ID_RUNTIME_ARG_NAME);
+    let expires_at: Option<u64> = runtime::get_named_arg(EXPIRES_AT_RUNTIME_ARG_NAME);
 
     let marketplace_contract_hash: ContractHash = {
         let ido_contract_hash_key: Key = runtime::get_named_arg(MARKETPLACE_CONTRACT_HASH_ARG_NAME);
@@ -46,7 +48,8 @@ fn call() {
         runtime_args! {
             COLLECTION_RUNTIME_ARG_NAME => collection_key,
             TOKEN_ID_RUNTIME_ARG_NAME => token_id,
-            AMOUNT_RUNTIME_ARG_NAME => amount
+            AMOUNT_RUNTIME_ARG_NAME => amount,
+            EXPIRES_AT_RUNTIME_ARG_NAME => expires_at
         },
     );
 }
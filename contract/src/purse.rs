@@ -54,6 +54,9 @@ pub(crate) fn checked_balance() -> U512 {
 }
 
 pub(crate) fn transfer(account: AccountHash, amount: U512) {
+    if amount.is_zero() {
+        return;
+    }
     let purse: URef = get_main_purse();
     system::transfer_from_purse_to_account(purse, account, amount, None).unwrap_or_revert();
     update_purse_balance();
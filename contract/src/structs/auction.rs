@@ -5,15 +5,19 @@ use casper_types::{
     CLType, CLTyped, ContractHash, U256, U512,
 };
 
-use super::bid::Bid;
+use super::{
+    bid::{Bid, BidStatus},
+    sealed_bid::SealedBid,
+};
 
 #[repr(u8)]
-#[derive(Copy, Clone, Debug)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
 pub enum AuctionType {
     Basic = 0,
     FixedPrice = 1,
     NoReserve = 2,
     Decreasing = 3,
+    Sealed = 4,
 }
 
 impl CLTyped for AuctionType {
@@ -55,6 +59,60 @@ impl AuctionType {
             1 => AuctionType::FixedPrice,
             2 => AuctionType::NoReserve,
             3 => AuctionType::Decreasing,
+            4 => AuctionType::Sealed,
+            _ => panic!(),
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum AuctionStatus {
+    Active = 0,
+    Sold = 1,
+    Unsold = 2,
+    Canceled = 3,
+}
+
+impl CLTyped for AuctionStatus {
+    fn cl_type() -> CLType {
+        CLType::U8
+    }
+}
+
+impl ToBytes for AuctionStatus {
+    fn to_bytes(&self) -> Result<alloc::vec::Vec<u8>, casper_types::bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend((*self as u8).to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        (*self as u8).serialized_length()
+    }
+
+    fn into_bytes(self) -> Result<alloc::vec::Vec<u8>, bytesrepr::Error>
+    where
+        Self: Sized,
+    {
+        self.to_bytes()
+    }
+}
+
+impl FromBytes for AuctionStatus {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (result, bytes) = u8::from_bytes(bytes).unwrap();
+        Ok((AuctionStatus::from(result), bytes))
+    }
+}
+
+impl AuctionStatus {
+    pub fn from(data: u8) -> Self {
+        match data {
+            0 => AuctionStatus::Active,
+            1 => AuctionStatus::Sold,
+            2 => AuctionStatus::Unsold,
+            3 => AuctionStatus::Canceled,
             _ => panic!(),
         }
     }
@@ -67,9 +125,18 @@ pub struct Auction {
     pub token_id: U256,
     pub auction_type: AuctionType,
     pub price: Option<U512>,
+    pub floor_price: Option<U512>,
     pub start_time: u64,
     pub end_time: Option<u64>,
+    pub reveal_end_time: Option<u64>,
+    pub second_price: bool,
+    pub extension_window: u64,
+    pub extension_duration: u64,
+    pub min_bid_increment: U512,
+    pub min_bid_increment_rate: U512,
     pub bids: Vec<Bid>,
+    pub sealed_bids: Vec<SealedBid>,
+    pub status: AuctionStatus,
 }
 
 impl CLTyped for Auction {
@@ -86,9 +153,18 @@ impl ToBytes for Auction {
         buffer.extend(self.token_id.to_bytes()?);
         buffer.extend(self.auction_type.to_bytes()?);
         buffer.extend(self.price.to_bytes()?);
+        buffer.extend(self.floor_price.to_bytes()?);
         buffer.extend(self.start_time.to_bytes()?);
         buffer.extend(self.end_time.to_bytes()?);
+        buffer.extend(self.reveal_end_time.to_bytes()?);
+        buffer.extend(self.second_price.to_bytes()?);
+        buffer.extend(self.extension_window.to_bytes()?);
+        buffer.extend(self.extension_duration.to_bytes()?);
+        buffer.extend(self.min_bid_increment.to_bytes()?);
+        buffer.extend(self.min_bid_increment_rate.to_bytes()?);
         buffer.extend(self.bids.to_bytes()?);
+        buffer.extend(self.sealed_bids.to_bytes()?);
+        buffer.extend(self.status.to_bytes()?);
         Ok(buffer)
     }
 
@@ -98,9 +174,18 @@ impl ToBytes for Auction {
             + self.token_id.serialized_length()
             + self.auction_type.serialized_length()
             + self.price.serialized_length()
+            + self.floor_price.serialized_length()
             + self.start_time.serialized_length()
             + self.end_time.serialized_length()
+            + self.reveal_end_time.serialized_length()
+            + self.second_price.serialized_length()
+            + self.extension_window.serialized_length()
+            + self.extension_duration.serialized_length()
+            + self.min_bid_increment.serialized_length()
+            + self.min_bid_increment_rate.serialized_length()
             + self.bids.serialized_length()
+            + self.sealed_bids.serialized_length()
+            + self.status.serialized_length()
     }
 
     fn into_bytes(self) -> Result<alloc::vec::Vec<u8>, bytesrepr::Error>
@@ -118,9 +203,18 @@ impl FromBytes for Auction {
         let (token_id, bytes) = U256::from_bytes(bytes)?;
         let (auction_type, bytes) = AuctionType::from_bytes(bytes)?;
         let (price, bytes) = Option::<U512>::from_bytes(bytes)?;
+        let (floor_price, bytes) = Option::<U512>::from_bytes(bytes)?;
         let (start_time, bytes) = u64::from_bytes(bytes)?;
         let (end_time, bytes) = Option::<u64>::from_bytes(bytes)?;
+        let (reveal_end_time, bytes) = Option::<u64>::from_bytes(bytes)?;
+        let (second_price, bytes) = bool::from_bytes(bytes)?;
+        let (extension_window, bytes) = u64::from_bytes(bytes)?;
+        let (extension_duration, bytes) = u64::from_bytes(bytes)?;
+        let (min_bid_increment, bytes) = U512::from_bytes(bytes)?;
+        let (min_bid_increment_rate, bytes) = U512::from_bytes(bytes)?;
         let (bids, bytes) = Vec::<Bid>::from_bytes(bytes)?;
+        let (sealed_bids, bytes) = Vec::<SealedBid>::from_bytes(bytes)?;
+        let (status, bytes) = AuctionStatus::from_bytes(bytes)?;
         Ok((
             Auction {
                 offerer,
@@ -128,11 +222,67 @@ impl FromBytes for Auction {
                 token_id,
                 auction_type,
                 price,
+                floor_price,
                 start_time,
                 end_time,
+                reveal_end_time,
+                second_price,
+                extension_window,
+                extension_duration,
+                min_bid_increment,
+                min_bid_increment_rate,
                 bids,
+                sealed_bids,
+                status,
             },
             bytes,
         ))
     }
 }
+
+impl Auction {
+    /// Returns the index of the bid currently escrowed as the highest one.
+    pub fn get_highest_bid_index(&self) -> Option<usize> {
+        self.bids
+            .iter()
+            .position(|bid| bid.status == BidStatus::Pending)
+    }
+
+    /// Returns the smallest bid that outbids `price`, by at least `min_bid_increment` and by at
+    /// least `min_bid_increment_rate` per mille of `price`.
+    pub fn get_min_next_bid(&self, price: U512) -> Option<U512> {
+        let rate_increment = price
+            .checked_mul(self.min_bid_increment_rate)?
+            .checked_div(U512::exp10(3))?;
+        let increment = self.min_bid_increment.max(rate_increment);
+        price.checked_add(increment)
+    }
+
+    /// Returns the index of the sealed bid committed by `account`.
+    pub fn get_sealed_bid_index_by_account(&self, account: AccountHash) -> Option<usize> {
+        self.sealed_bids
+            .iter()
+            .position(|sealed_bid| sealed_bid.offerer == account)
+    }
+
+    /// Returns the price of a decreasing auction at `time`, falling linearly from `price` at
+    /// `start_time` to `floor_price` at `end_time`.
+    pub fn get_decreasing_price(&self, time: u64) -> Option<U512> {
+        let start_price = self.price?;
+        let floor_price = self.floor_price?;
+        let end_time = self.end_time?;
+        if time <= self.start_time {
+            return Some(start_price);
+        }
+        if time >= end_time {
+            return Some(floor_price);
+        }
+        let elapsed = U512::from(time - self.start_time);
+        let duration = U512::from(end_time - self.start_time);
+        let discount = start_price
+            .checked_sub(floor_price)?
+            .checked_mul(elapsed)?
+            .checked_div(duration)?;
+        start_price.checked_sub(discount)
+    }
+}
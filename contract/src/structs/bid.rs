@@ -64,6 +64,7 @@ pub struct Bid {
     pub price: U512,
     pub bid_time: u64,
     pub status: BidStatus,
+    pub expires_at: Option<u64>,
 }
 
 impl CLTyped for Bid {
@@ -78,11 +79,13 @@ impl FromBytes for Bid {
         let (price, bytes) = U512::from_bytes(bytes)?;
         let (bid_time, bytes) = u64::from_bytes(bytes)?;
         let (status, bytes) = BidStatus::from_bytes(bytes)?;
+        let (expires_at, bytes) = Option::<u64>::from_bytes(bytes)?;
         let body = Bid {
             offerer,
             price,
             bid_time,
             status,
+            expires_at,
         };
         Ok((body, bytes))
     }
@@ -95,6 +98,7 @@ impl ToBytes for Bid {
         buffer.extend(self.price.to_bytes()?);
         buffer.extend(self.bid_time.to_bytes()?);
         buffer.extend(self.status.to_bytes()?);
+        buffer.extend(self.expires_at.to_bytes()?);
 
         Ok(buffer)
     }
@@ -104,5 +108,14 @@ impl ToBytes for Bid {
             + self.price.serialized_length()
             + self.bid_time.serialized_length()
             + self.status.serialized_length()
+            + self.expires_at.serialized_length()
+    }
+}
+
+impl Bid {
+    /// Whether the bid has an expiry at or before `time`.
+    pub fn is_expired(&self, time: u64) -> bool {
+        self.expires_at
+            .map_or(false, |expires_at| expires_at <= time)
     }
 }
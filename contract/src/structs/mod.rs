@@ -1,4 +1,7 @@
 pub mod auction;
 pub mod bid;
+pub mod bundle;
+pub mod collection_offer;
 pub mod offer;
 pub mod order;
+pub mod sealed_bid;
@@ -0,0 +1,67 @@
+use alloc::vec::Vec;
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, U512,
+};
+
+use super::bid::BidStatus;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct SealedBid {
+    pub offerer: AccountHash,
+    pub commitment: [u8; 32],
+    pub deposit: U512,
+    pub price: Option<U512>,
+    pub bid_time: u64,
+    pub status: BidStatus,
+}
+
+impl CLTyped for SealedBid {
+    fn cl_type() -> casper_types::CLType {
+        CLType::Any
+    }
+}
+
+impl FromBytes for SealedBid {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (offerer, bytes) = AccountHash::from_bytes(bytes)?;
+        let (commitment, bytes) = <[u8; 32]>::from_bytes(bytes)?;
+        let (deposit, bytes) = U512::from_bytes(bytes)?;
+        let (price, bytes) = Option::<U512>::from_bytes(bytes)?;
+        let (bid_time, bytes) = u64::from_bytes(bytes)?;
+        let (status, bytes) = BidStatus::from_bytes(bytes)?;
+        let body = SealedBid {
+            offerer,
+            commitment,
+            deposit,
+            price,
+            bid_time,
+            status,
+        };
+        Ok((body, bytes))
+    }
+}
+
+impl ToBytes for SealedBid {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.offerer.to_bytes()?);
+        buffer.extend(self.commitment.to_bytes()?);
+        buffer.extend(self.deposit.to_bytes()?);
+        buffer.extend(self.price.to_bytes()?);
+        buffer.extend(self.bid_time.to_bytes()?);
+        buffer.extend(self.status.to_bytes()?);
+
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.offerer.serialized_length()
+            + self.commitment.serialized_length()
+            + self.deposit.serialized_length()
+            + self.price.serialized_length()
+            + self.bid_time.serialized_length()
+            + self.status.serialized_length()
+    }
+}
@@ -0,0 +1,72 @@
+use alloc::vec::Vec;
+
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, ContractHash, U256, U512,
+};
+
+#[derive(Clone, Debug)]
+pub struct Bundle {
+    pub bundle_id: u64,
+    pub offerer: AccountHash,
+    pub tokens: Vec<(ContractHash, U256)>,
+    pub price: U512,
+    pub is_active: bool,
+    pub buyer: Option<AccountHash>,
+    pub sold_at: Option<u64>,
+}
+
+impl CLTyped for Bundle {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl FromBytes for Bundle {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (bundle_id, bytes) = u64::from_bytes(bytes)?;
+        let (offerer, bytes) = AccountHash::from_bytes(bytes)?;
+        let (tokens, bytes) = Vec::<(ContractHash, U256)>::from_bytes(bytes)?;
+        let (price, bytes) = U512::from_bytes(bytes)?;
+        let (is_active, bytes) = bool::from_bytes(bytes)?;
+        let (buyer, bytes) = Option::<AccountHash>::from_bytes(bytes)?;
+        let (sold_at, bytes) = Option::<u64>::from_bytes(bytes)?;
+
+        let body = Bundle {
+            bundle_id,
+            offerer,
+            tokens,
+            price,
+            is_active,
+            buyer,
+            sold_at,
+        };
+        Ok((body, bytes))
+    }
+}
+
+impl ToBytes for Bundle {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.bundle_id.to_bytes()?);
+        buffer.extend(self.offerer.to_bytes()?);
+        buffer.extend(self.tokens.to_bytes()?);
+        buffer.extend(self.price.to_bytes()?);
+        buffer.extend(self.is_active.to_bytes()?);
+        buffer.extend(self.buyer.to_bytes()?);
+        buffer.extend(self.sold_at.to_bytes()?);
+
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.bundle_id.serialized_length()
+            + self.offerer.serialized_length()
+            + self.tokens.serialized_length()
+            + self.price.serialized_length()
+            + self.is_active.serialized_length()
+            + self.buyer.serialized_length()
+            + self.sold_at.serialized_length()
+    }
+}
@@ -0,0 +1,82 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, ContractHash, U512,
+};
+
+#[derive(Clone, Debug)]
+pub struct CollectionOffer {
+    pub collection_offer_id: u64,
+    pub collection: ContractHash,
+    pub offerer: AccountHash,
+    pub price: U512,
+    pub quantity: u64,
+    pub filled: u64,
+    pub bid_time: u64,
+    pub is_active: bool,
+    pub traits: BTreeMap<String, String>,
+}
+
+impl CLTyped for CollectionOffer {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl FromBytes for CollectionOffer {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (collection_offer_id, bytes) = u64::from_bytes(bytes)?;
+        let (collection, bytes) = ContractHash::from_bytes(bytes)?;
+        let (offerer, bytes) = AccountHash::from_bytes(bytes)?;
+        let (price, bytes) = U512::from_bytes(bytes)?;
+        let (quantity, bytes) = u64::from_bytes(bytes)?;
+        let (filled, bytes) = u64::from_bytes(bytes)?;
+        let (bid_time, bytes) = u64::from_bytes(bytes)?;
+        let (is_active, bytes) = bool::from_bytes(bytes)?;
+        let (traits, bytes) = BTreeMap::<String, String>::from_bytes(bytes)?;
+
+        let body = CollectionOffer {
+            collection_offer_id,
+            collection,
+            offerer,
+            price,
+            quantity,
+            filled,
+            bid_time,
+            is_active,
+            traits,
+        };
+        Ok((body, bytes))
+    }
+}
+
+impl ToBytes for CollectionOffer {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.collection_offer_id.to_bytes()?);
+        buffer.extend(self.collection.to_bytes()?);
+        buffer.extend(self.offerer.to_bytes()?);
+        buffer.extend(self.price.to_bytes()?);
+        buffer.extend(self.quantity.to_bytes()?);
+        buffer.extend(self.filled.to_bytes()?);
+        buffer.extend(self.bid_time.to_bytes()?);
+        buffer.extend(self.is_active.to_bytes()?);
+        buffer.extend(self.traits.to_bytes()?);
+
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.collection_offer_id.serialized_length()
+            + self.collection.serialized_length()
+            + self.offerer.serialized_length()
+            + self.price.serialized_length()
+            + self.quantity.serialized_length()
+            + self.filled.serialized_length()
+            + self.bid_time.serialized_length()
+            + self.is_active.serialized_length()
+            + self.traits.serialized_length()
+    }
+}
@@ -2,8 +2,11 @@ extern crate alloc;
 
 mod auction;
 mod bid;
+mod bundle;
+mod collection_offer;
 mod offer;
 mod order;
+mod sealed_bid;
 
 mod meta {
     use std::collections::BTreeMap;
@@ -42,22 +45,37 @@ mod meta {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
 
     use casper_engine_test_support::{
         DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNT_ADDR,
         DEFAULT_PAYMENT, DEFAULT_RUN_GENESIS_REQUEST,
     };
-    use casper_execution_engine::core::engine_state::ExecuteRequest;
+    use casper_execution_engine::core::{
+        engine_state::{self, ExecuteRequest},
+        execution,
+    };
     use casper_types::{
-        account::AccountHash, bytesrepr::FromBytes, runtime_args, CLTyped, ContractHash,
-        ContractPackageHash, Key, PublicKey, RuntimeArgs, SecretKey, U256, U512,
+        account::AccountHash,
+        bytesrepr::{FromBytes, ToBytes},
+        crypto, runtime_args, ApiError, CLTyped, ContractHash, ContractPackageHash, Key, PublicKey,
+        RuntimeArgs, SecretKey, U256, U512,
     };
 
-    use crate::{auction::Auction, meta, offer::Offer, order::Order};
+    use crate::{
+        auction::{Auction, AuctionStatus},
+        bid::BidStatus,
+        bundle::Bundle,
+        collection_offer::CollectionOffer,
+        meta,
+        offer::Offer,
+        order::Order,
+    };
 
     // KEY NAMES
     const CONTRACT_NAME_KEY_NAME: &str = "casper_nft_marketplace";
     const RESULT_KEY_NAME: &str = "result";
+    const AUCTIONS_KEY_NAME: &str = "auctions";
 
     // ENTRY
     const CREATE_ORDER_ENTRY_NAME: &str = "create_order";
@@ -69,13 +87,25 @@ mod tests {
     const PRICE_RUNTIME_ARG_NAME: &str = "price";
     const AMOUNT_RUNTIME_ARG_NAME: &str = "amount";
     const MARKETPLACE_CONTRACT_HASH_ARG_NAME: &str = "marketplace_contract_hash";
-    const BID_ID_RUNTIME_ARG_NAME: &str = "bid_id";
+    const BIDDER_RUNTIME_ARG_NAME: &str = "bidder";
+
+    // ERROR
+    const NOT_VALID_AMOUNT_ERROR: u16 = 45;
+    const BID_INCREMENT_TOO_LOW_ERROR: u16 = 61;
+    const UNEXPECTED_PRICE_ERROR: u16 = 62;
+    const ORDER_EXPIRED_ERROR: u16 = 63;
+    const NOT_RESERVED_BUYER_ERROR: u16 = 67;
 
     const CONTRACT_WASM: &str = "contract.wasm";
     const PRE_BUY_ORDER_CONTRACT_WASM: &str = "pre_buy_order.wasm";
+    const PRE_BUY_ORDERS_CONTRACT_WASM: &str = "pre_buy_orders.wasm";
+    const PRE_BUY_BUNDLE_CONTRACT_WASM: &str = "pre_buy_bundle.wasm";
     const PER_CREATE_OFFER_CONTRACT_WASM: &str = "pre_create_offer.wasm";
+    const PRE_CREATE_COLLECTION_OFFER_CONTRACT_WASM: &str = "pre_create_collection_offer.wasm";
+    const PRE_PLACE_BID_CONTRACT_WASM: &str = "pre_place_bid.wasm";
     const CEP47_CONTRACT_WASM: &str = "cep47-token.wasm";
     const AUTHORIZE_ACCOUNT_CONTRACT_WASM: &str = "authorize_account.wasm";
+    const GET_AUCTIONS_CONTRACT_WASM: &str = "get_auctions.wasm";
 
     const NFT_NAME: &str = "DragonsNFT";
     const NFT_SYMBOL: &str = "DGNFT";
@@ -197,6 +227,14 @@ mod tests {
         *contract_hash
     }
 
+    fn balance_of(builder: &InMemoryWasmTestBuilder, account: AccountHash) -> U512 {
+        let purse = builder
+            .get_account(account)
+            .expect("should have account")
+            .main_purse();
+        builder.get_purse_balance(purse)
+    }
+
     fn get_test_result<T: FromBytes + CLTyped>(
         builder: &mut InMemoryWasmTestBuilder,
         contract_hash: ContractHash,
@@ -221,6 +259,24 @@ mod tests {
         builder.exec(exec_request).expect_success().commit();
     }
 
+    fn expect_revert(
+        builder: &mut InMemoryWasmTestBuilder,
+        exec_request: ExecuteRequest,
+        expected: u16,
+    ) {
+        builder.exec(exec_request).expect_failure().commit();
+        let error = builder.get_error().unwrap();
+        assert!(
+            matches!(
+                error,
+                engine_state::Error::Exec(execution::Error::Revert(ApiError::User(code)))
+                    if code == expected
+            ),
+            "{:?}",
+            error
+        );
+    }
+
     fn authorize_account(
         builder: &mut InMemoryWasmTestBuilder,
         context: TestContext,
@@ -287,6 +343,26 @@ mod tests {
                 COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
                 TOKEN_ID_RUNTIME_ARG_NAME => token_id,
                 PRICE_RUNTIME_ARG_NAME => price,
+                "expires_at" => Option::<u64>::None,
+                "reserved_for" => Option::<AccountHash>::None,
+                "custodial" => true,
+            },
+        );
+    }
+
+    fn create_non_custodial_order(builder: &mut InMemoryWasmTestBuilder, context: TestContext) {
+        call_contract(
+            builder,
+            context.marketplace_contract,
+            *DEFAULT_ACCOUNT_ADDR,
+            CREATE_ORDER_ENTRY_NAME,
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => U256::zero(),
+                PRICE_RUNTIME_ARG_NAME => U512::from(1000).checked_mul(U512::exp10(9)).unwrap(),
+                "expires_at" => Option::<u64>::None,
+                "reserved_for" => Option::<AccountHash>::None,
+                "custodial" => false,
             },
         );
     }
@@ -304,14 +380,20 @@ mod tests {
         );
     }
 
-    fn pre_buy_order(builder: &mut InMemoryWasmTestBuilder, context: TestContext, token_id: U256) {
+    fn pre_buy_order(
+        builder: &mut InMemoryWasmTestBuilder,
+        context: TestContext,
+        buyer: AccountHash,
+        token_id: U256,
+    ) {
         let install_pre_buy_order_contract = ExecuteRequestBuilder::standard(
-            *DEFAULT_ACCOUNT_ADDR,
+            buyer,
             PRE_BUY_ORDER_CONTRACT_WASM,
             runtime_args! {
                 COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
                 TOKEN_ID_RUNTIME_ARG_NAME => token_id,
                 AMOUNT_RUNTIME_ARG_NAME => U512::from(1000).checked_mul(U512::exp10(9)).unwrap(),
+                "expected_price" => U512::from(1000).checked_mul(U512::exp10(9)).unwrap(),
                 MARKETPLACE_CONTRACT_HASH_ARG_NAME => Key::from(context.marketplace_contract)
             },
         )
@@ -323,6 +405,93 @@ mod tests {
             .commit();
     }
 
+    fn pre_buy_orders(
+        builder: &mut InMemoryWasmTestBuilder,
+        context: TestContext,
+        buyer: AccountHash,
+        orders: Vec<(Key, U256, U512)>,
+        amount: U512,
+    ) {
+        let install_pre_buy_orders_contract = ExecuteRequestBuilder::standard(
+            buyer,
+            PRE_BUY_ORDERS_CONTRACT_WASM,
+            runtime_args! {
+                "orders" => orders,
+                AMOUNT_RUNTIME_ARG_NAME => amount,
+                MARKETPLACE_CONTRACT_HASH_ARG_NAME => Key::from(context.marketplace_contract)
+            },
+        )
+        .build();
+
+        builder
+            .exec(install_pre_buy_orders_contract)
+            .expect_success()
+            .commit();
+    }
+
+    fn pre_buy_bundle(
+        builder: &mut InMemoryWasmTestBuilder,
+        context: TestContext,
+        buyer: AccountHash,
+        bundle_id: u64,
+        price: U512,
+    ) {
+        let install_pre_buy_bundle_contract = ExecuteRequestBuilder::standard(
+            buyer,
+            PRE_BUY_BUNDLE_CONTRACT_WASM,
+            runtime_args! {
+                "bundle_id" => bundle_id,
+                AMOUNT_RUNTIME_ARG_NAME => price,
+                MARKETPLACE_CONTRACT_HASH_ARG_NAME => Key::from(context.marketplace_contract)
+            },
+        )
+        .build();
+
+        builder
+            .exec(install_pre_buy_bundle_contract)
+            .expect_success()
+            .commit();
+    }
+
+    fn get_auctions(
+        builder: &mut InMemoryWasmTestBuilder,
+        context: TestContext,
+        entry_point: &str,
+        collection: Option<Key>,
+        seller: Option<Key>,
+        offset: u64,
+        limit: u64,
+    ) -> Vec<Auction> {
+        let caller = account(3);
+        let install_get_auctions_contract = ExecuteRequestBuilder::standard(
+            caller,
+            GET_AUCTIONS_CONTRACT_WASM,
+            runtime_args! {
+                "entry_point" => entry_point.to_string(),
+                COLLECTION_RUNTIME_ARG_NAME => collection,
+                "seller" => seller,
+                "offset" => offset,
+                "limit" => limit,
+                MARKETPLACE_CONTRACT_HASH_ARG_NAME => Key::from(context.marketplace_contract)
+            },
+        )
+        .build();
+
+        builder
+            .exec(install_get_auctions_contract)
+            .expect_success()
+            .commit();
+
+        builder
+            .query(None, Key::from(caller), &[AUCTIONS_KEY_NAME.to_string()])
+            .expect("should have auctions")
+            .as_cl_value()
+            .expect("should be a cl value")
+            .clone()
+            .into_t()
+            .expect("should be auctions")
+    }
+
     fn mint_nft(builder: &mut InMemoryWasmTestBuilder, context: TestContext) {
         call_contract(
             builder,
@@ -377,6 +546,7 @@ mod tests {
                 COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
                 TOKEN_ID_RUNTIME_ARG_NAME => token_id,
                 AMOUNT_RUNTIME_ARG_NAME => price,
+                "expires_at" => Option::<u64>::None,
                 MARKETPLACE_CONTRACT_HASH_ARG_NAME => Key::from(context.marketplace_contract)
             },
         )
@@ -388,6 +558,34 @@ mod tests {
             .commit();
     }
 
+    fn pre_create_collection_offer(
+        builder: &mut InMemoryWasmTestBuilder,
+        context: TestContext,
+        offerer: AccountHash,
+        price: U512,
+        quantity: u64,
+        traits: BTreeMap<String, String>,
+    ) {
+        let install_pre_create_collection_offer_contract = ExecuteRequestBuilder::standard(
+            offerer,
+            PRE_CREATE_COLLECTION_OFFER_CONTRACT_WASM,
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                PRICE_RUNTIME_ARG_NAME => price,
+                "quantity" => quantity,
+                "traits" => traits,
+                AMOUNT_RUNTIME_ARG_NAME => price.checked_mul(U512::from(quantity)).unwrap(),
+                MARKETPLACE_CONTRACT_HASH_ARG_NAME => Key::from(context.marketplace_contract)
+            },
+        )
+        .build();
+
+        builder
+            .exec(install_pre_create_collection_offer_contract)
+            .expect_success()
+            .commit();
+    }
+
     fn _create_offer(
         builder: &mut InMemoryWasmTestBuilder,
         context: TestContext,
@@ -403,7 +601,8 @@ mod tests {
             runtime_args! {
                 COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
                 TOKEN_ID_RUNTIME_ARG_NAME => token_id,
-                AMOUNT_RUNTIME_ARG_NAME => price
+                AMOUNT_RUNTIME_ARG_NAME => price,
+                "expires_at" => Option::<u64>::None
             },
         );
     }
@@ -430,9 +629,9 @@ mod tests {
     fn accept_offer(
         builder: &mut InMemoryWasmTestBuilder,
         context: TestContext,
-
         token_id: U256,
-        bid_id: u8,
+        bidder: AccountHash,
+        min_price: U512,
     ) {
         call_contract(
             builder,
@@ -442,7 +641,8 @@ mod tests {
             runtime_args! {
                 COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
                 TOKEN_ID_RUNTIME_ARG_NAME => token_id,
-                BID_ID_RUNTIME_ARG_NAME => bid_id
+                BIDDER_RUNTIME_ARG_NAME => bidder,
+                "min_price" => min_price
             },
         );
     }
@@ -454,6 +654,7 @@ mod tests {
         token_id: U256,
         auction_type: u8,
         price: Option<U512>,
+        floor_price: Option<U512>,
         start_time: u64,
         end_time: Option<u64>,
     ) {
@@ -467,194 +668,2042 @@ mod tests {
                 TOKEN_ID_RUNTIME_ARG_NAME => token_id,
                 "auction_type" => auction_type,
                 "price" => price,
+                "floor_price" => floor_price,
                 "start_time" => start_time,
-                "end_time" => end_time
+                "end_time" => end_time,
+                "reveal_end_time" => Option::<u64>::None,
+                "second_price" => false,
+                "extension_window" => Option::<u64>::None,
+                "extension_duration" => Option::<u64>::None,
+                "min_bid_increment" => Option::<U512>::None,
+                "min_bid_increment_rate" => Option::<U512>::None
+            },
+        );
+    }
+
+    fn settle_auction(
+        builder: &mut InMemoryWasmTestBuilder,
+        context: TestContext,
+        caller: AccountHash,
+        token_id: U256,
+        block_time: u64,
+    ) {
+        let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+            caller,
+            context.marketplace_contract,
+            "settle_auction",
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => token_id,
+            },
+        )
+        .with_block_time(block_time)
+        .build();
+        builder.exec(exec_request).expect_success().commit();
+    }
+
+    fn pre_place_bid(
+        builder: &mut InMemoryWasmTestBuilder,
+        context: TestContext,
+        bidder: AccountHash,
+        token_id: U256,
+        amount: U512,
+        block_time: u64,
+    ) {
+        let install_pre_place_bid_contract = ExecuteRequestBuilder::standard(
+            bidder,
+            PRE_PLACE_BID_CONTRACT_WASM,
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => token_id,
+                AMOUNT_RUNTIME_ARG_NAME => amount,
+                "commitment" => Option::<[u8; 32]>::None,
+                MARKETPLACE_CONTRACT_HASH_ARG_NAME => Key::from(context.marketplace_contract)
+            },
+        )
+        .with_block_time(block_time)
+        .build();
+
+        builder
+            .exec(install_pre_place_bid_contract)
+            .expect_success()
+            .commit();
+    }
+
+    fn create_sealed_auction(
+        builder: &mut InMemoryWasmTestBuilder,
+        context: TestContext,
+        token_id: U256,
+        end_time: u64,
+        reveal_end_time: u64,
+        second_price: bool,
+        reserve: Option<U512>,
+    ) {
+        call_contract(
+            builder,
+            context.marketplace_contract,
+            *DEFAULT_ACCOUNT_ADDR,
+            "create_auction",
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => token_id,
+                "auction_type" => 4u8,
+                "price" => reserve,
+                "floor_price" => Option::<U512>::None,
+                "start_time" => 0u64,
+                "end_time" => Some(end_time),
+                "reveal_end_time" => Some(reveal_end_time),
+                "second_price" => second_price,
+                "extension_window" => Option::<u64>::None,
+                "extension_duration" => Option::<u64>::None,
+                "min_bid_increment" => Option::<U512>::None,
+                "min_bid_increment_rate" => Option::<U512>::None
+            },
+        );
+    }
+
+    fn make_commitment(bidder: AccountHash, price: U512, salt: &str) -> [u8; 32] {
+        let mut preimage = Vec::new();
+        preimage.append(&mut bidder.to_bytes().unwrap());
+        preimage.append(&mut price.to_bytes().unwrap());
+        preimage.append(&mut salt.to_string().to_bytes().unwrap());
+        crypto::blake2b(preimage)
+    }
+
+    fn commit_bid(
+        builder: &mut InMemoryWasmTestBuilder,
+        context: TestContext,
+        bidder: AccountHash,
+        token_id: U256,
+        deposit: U512,
+        commitment: [u8; 32],
+        block_time: u64,
+    ) {
+        let install_pre_place_bid_contract = ExecuteRequestBuilder::standard(
+            bidder,
+            PRE_PLACE_BID_CONTRACT_WASM,
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => token_id,
+                AMOUNT_RUNTIME_ARG_NAME => deposit,
+                "commitment" => Some(commitment),
+                MARKETPLACE_CONTRACT_HASH_ARG_NAME => Key::from(context.marketplace_contract)
+            },
+        )
+        .with_block_time(block_time)
+        .build();
+
+        builder
+            .exec(install_pre_place_bid_contract)
+            .expect_success()
+            .commit();
+    }
+
+    fn reveal_bid(
+        builder: &mut InMemoryWasmTestBuilder,
+        context: TestContext,
+        bidder: AccountHash,
+        token_id: U256,
+        price: U512,
+        salt: &str,
+        block_time: u64,
+    ) {
+        let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+            bidder,
+            context.marketplace_contract,
+            "reveal_bid",
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => token_id,
+                PRICE_RUNTIME_ARG_NAME => price,
+                "salt" => salt.to_string(),
             },
+        )
+        .with_block_time(block_time)
+        .build();
+        builder.exec(exec_request).expect_success().commit();
+    }
+
+    /// Runs a sealed auction where account 2 bids 3 CSPR and account 3 bids 4 CSPR, each with a
+    /// 5 CSPR deposit, while account 4 commits a 2 CSPR deposit without revealing. Returns the
+    /// settled auction and how much each bidder got back at settlement.
+    fn run_sealed_auction(second_price: bool) -> (Auction, U512, U512, U512) {
+        let (mut builder, context) = setup();
+        mint_nft(&mut builder, context);
+        approve_nft(&mut builder, context);
+
+        let token_id = U256::zero();
+        let end_time = 1653506150007u64;
+        let reveal_end_time = end_time + 86_400_000u64;
+        create_sealed_auction(
+            &mut builder,
+            context,
+            token_id,
+            end_time,
+            reveal_end_time,
+            second_price,
+            None,
+        );
+
+        let deposit = U512::from(5u8).checked_mul(U512::exp10(9)).unwrap();
+        let low_price = U512::from(3u8).checked_mul(U512::exp10(9)).unwrap();
+        let high_price = U512::from(4u8).checked_mul(U512::exp10(9)).unwrap();
+        let unrevealed_deposit = U512::from(2u8).checked_mul(U512::exp10(9)).unwrap();
+        commit_bid(
+            &mut builder,
+            context,
+            account(2),
+            token_id,
+            deposit,
+            make_commitment(account(2), low_price, "low"),
+            1u64,
+        );
+        commit_bid(
+            &mut builder,
+            context,
+            account(3),
+            token_id,
+            deposit,
+            make_commitment(account(3), high_price, "high"),
+            2u64,
+        );
+        commit_bid(
+            &mut builder,
+            context,
+            account(4),
+            token_id,
+            unrevealed_deposit,
+            make_commitment(account(4), unrevealed_deposit, "hidden"),
+            3u64,
+        );
+
+        reveal_bid(
+            &mut builder,
+            context,
+            account(2),
+            token_id,
+            low_price,
+            "low",
+            end_time,
+        );
+        reveal_bid(
+            &mut builder,
+            context,
+            account(3),
+            token_id,
+            high_price,
+            "high",
+            end_time,
         );
+
+        let before: Vec<U512> = (2..5u8)
+            .map(|index| balance_of(&builder, account(index)))
+            .collect();
+        settle_auction(&mut builder, context, account(5), token_id, reveal_end_time);
+        let after: Vec<U512> = (2..5u8)
+            .map(|index| balance_of(&builder, account(index)))
+            .collect();
+
+        let purse_balance: U512 = builder.get_value(context.marketplace_contract, "purse_balance");
+        assert_eq!(purse_balance, U512::zero());
+
+        let auction: Auction = get_test_result(&mut builder, context.marketplace_contract);
+        (
+            auction,
+            after[0] - before[0],
+            after[1] - before[1],
+            after[2] - before[2],
+        )
     }
 
     #[test]
     fn should_create_auction() {
         let (mut builder, context) = setup();
-        let offerer = account(1);
+        mint_nft(&mut builder, context);
+        approve_nft(&mut builder, context);
 
         let token_id = U256::zero();
-        let auction_type = 1u8;
+        let auction_type = 0u8;
         let price = Some(U512::from(3u8).checked_mul(U512::exp10(9)).unwrap());
-        let start_time = 1653506150007u64;
-        let end_time: Option<u64> = None;
+        let start_time = 0u64;
+        let end_time = Some(1653506150007u64);
 
         create_auction(
             &mut builder,
             context,
-            offerer,
+            *DEFAULT_ACCOUNT_ADDR,
             token_id,
             auction_type,
             price,
+            None,
             start_time,
             end_time,
         );
         let auction: Auction = get_test_result(&mut builder, context.marketplace_contract);
         println!("{:?}", auction);
         assert!(auction.bids.len() == 0);
+        assert_eq!(auction.status, AuctionStatus::Active);
+        assert_eq!(auction.extension_window, 600_000u64);
+        assert_eq!(auction.extension_duration, 600_000u64);
+        assert_eq!(auction.min_bid_increment, U512::zero());
+        assert_eq!(auction.min_bid_increment_rate, U512::from(10u8));
     }
 
     #[test]
-    fn should_set_treasury_wallet() {
+    fn should_extend_auction_on_late_bid() {
         let (mut builder, context) = setup();
-        let admin = account(1);
-        let treasury_wallet = account(1);
-        authorize_account(&mut builder, context, admin);
-        set_treasury_wallet(
+        mint_nft(&mut builder, context);
+        approve_nft(&mut builder, context);
+
+        let token_id = U256::zero();
+        let end_time = 1653506150007u64;
+        create_auction(
             &mut builder,
             context,
-            admin,
-            treasury_wallet.to_formatted_string(),
+            *DEFAULT_ACCOUNT_ADDR,
+            token_id,
+            0u8,
+            None,
+            None,
+            0u64,
+            Some(end_time),
+        );
+        // Lands inside the default 10 minute window
+        let price = U512::from(2u8).checked_mul(U512::exp10(9)).unwrap();
+        pre_place_bid(
+            &mut builder,
+            context,
+            account(2),
+            token_id,
+            price,
+            end_time - 1_000,
+        );
+
+        let extended_end_time = end_time + 600_000u64;
+        let auction: Auction = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", auction);
+        assert_eq!(auction.end_time, Some(extended_end_time));
+        let on_auctions: Vec<(ContractHash, U256, (AccountHash, u64))> =
+            builder.get_value(context.marketplace_contract, "on_auctions");
+        assert_eq!(
+            on_auctions,
+            vec![(
+                context.nft_contract_hash,
+                token_id,
+                (*DEFAULT_ACCOUNT_ADDR, extended_end_time)
+            )]
         );
     }
 
     #[test]
-    fn should_set_fee() {
+    fn should_extend_auction_with_own_window() {
         let (mut builder, context) = setup();
-        let admin = account(1);
-        authorize_account(&mut builder, context, admin);
-        set_fee(&mut builder, context, admin, U512::from(25));
-    }
+        mint_nft(&mut builder, context);
+        approve_nft(&mut builder, context);
 
-    #[test]
-    fn should_create_offer() {
-        let (mut builder, context) = setup();
-        pre_create_offer(
+        let token_id = U256::zero();
+        let end_time = 1653506150007u64;
+        call_contract(
             &mut builder,
-            context,
+            context.marketplace_contract,
             *DEFAULT_ACCOUNT_ADDR,
-            U256::zero(),
-            U512::from(2).checked_mul(U512::exp10(9)).unwrap(),
+            "create_auction",
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => token_id,
+                "auction_type" => 0u8,
+                "price" => Option::<U512>::None,
+                "floor_price" => Option::<U512>::None,
+                "start_time" => 0u64,
+                "end_time" => Some(end_time),
+                "reveal_end_time" => Option::<u64>::None,
+                "second_price" => false,
+                "extension_window" => Some(5_000u64),
+                "extension_duration" => Some(60_000u64),
+                "min_bid_increment" => Option::<U512>::None,
+                "min_bid_increment_rate" => Option::<U512>::None
+            },
         );
-
-        pre_create_offer(
+        // Outside the 5 second window the deadline stays put
+        let price = U512::from(2u8).checked_mul(U512::exp10(9)).unwrap();
+        pre_place_bid(
             &mut builder,
             context,
             account(2),
-            U256::zero(),
-            U512::from(3).checked_mul(U512::exp10(9)).unwrap(),
+            token_id,
+            price,
+            end_time - 10_000,
         );
-        let offer: Offer = get_test_result(&mut builder, context.marketplace_contract);
-        println!("{:?}", offer);
-        assert!(offer.bids.len() == 2);
+        let auction: Auction = get_test_result(&mut builder, context.marketplace_contract);
+        assert_eq!(auction.end_time, Some(end_time));
+
+        let price = U512::from(3u8).checked_mul(U512::exp10(9)).unwrap();
+        pre_place_bid(
+            &mut builder,
+            context,
+            account(3),
+            token_id,
+            price,
+            end_time - 1_000,
+        );
+
+        let extended_end_time = end_time + 60_000u64;
+        let auction: Auction = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", auction);
+        assert_eq!(auction.extension_window, 5_000u64);
+        assert_eq!(auction.end_time, Some(extended_end_time));
+        let on_auctions: Vec<(ContractHash, U256, (AccountHash, u64))> =
+            builder.get_value(context.marketplace_contract, "on_auctions");
+        assert_eq!(on_auctions[0].2, (*DEFAULT_ACCOUNT_ADDR, extended_end_time));
     }
 
     #[test]
-    fn should_cancel_offer() {
+    fn should_settle_auction_without_bids() {
         let (mut builder, context) = setup();
-        pre_create_offer(
+        mint_nft(&mut builder, context);
+        approve_nft(&mut builder, context);
+
+        let token_id = U256::zero();
+        let end_time = 1653506150007u64;
+        create_auction(
             &mut builder,
             context,
             *DEFAULT_ACCOUNT_ADDR,
-            U256::zero(),
-            U512::from(2).checked_mul(U512::exp10(9)).unwrap(),
+            token_id,
+            0u8,
+            None,
+            None,
+            0u64,
+            Some(end_time),
         );
+        settle_auction(&mut builder, context, account(2), token_id, end_time);
 
-        pre_create_offer(
+        let auction: Auction = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", auction);
+        assert_eq!(auction.status, AuctionStatus::Unsold);
+    }
+
+    #[test]
+    fn should_not_sell_auction_below_reserve() {
+        let (mut builder, context) = setup();
+        mint_nft(&mut builder, context);
+        approve_nft(&mut builder, context);
+
+        let token_id = U256::zero();
+        let end_time = 1653506150007u64;
+        let reserve = U512::from(5u8).checked_mul(U512::exp10(9)).unwrap();
+        create_auction(
             &mut builder,
             context,
-            account(2),
-            U256::zero(),
-            U512::from(3).checked_mul(U512::exp10(9)).unwrap(),
+            *DEFAULT_ACCOUNT_ADDR,
+            token_id,
+            0u8,
+            Some(reserve),
+            None,
+            0u64,
+            Some(end_time),
         );
+        let price = U512::from(2u8).checked_mul(U512::exp10(9)).unwrap();
+        pre_place_bid(&mut builder, context, account(2), token_id, price, 1u64);
 
-        cancel_offer(&mut builder, context, account(2), U256::zero());
+        let before = balance_of(&builder, account(2));
+        settle_auction(&mut builder, context, account(3), token_id, end_time);
+        let after = balance_of(&builder, account(2));
 
-        let offer: Offer = get_test_result(&mut builder, context.marketplace_contract);
-        println!("{:?}", offer);
-        assert!(offer.bids.len() == 1);
+        let auction: Auction = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", auction);
+        assert_eq!(auction.status, AuctionStatus::Unsold);
+        assert_eq!(auction.bids[0].status, BidStatus::NotAccepted);
+        assert_eq!(after - before, price);
+
+        // The token is back with the seller, who can list it again
+        approve_nft(&mut builder, context);
+        create_order(&mut builder, context, token_id, reserve);
     }
 
     #[test]
-    fn should_accept_offer() {
+    fn should_sell_no_reserve_auction_to_bidder() {
         let (mut builder, context) = setup();
-        pre_create_offer(
+        mint_nft(&mut builder, context);
+        approve_nft(&mut builder, context);
+
+        let token_id = U256::zero();
+        let end_time = 1653506150007u64;
+        create_auction(
             &mut builder,
             context,
             *DEFAULT_ACCOUNT_ADDR,
-            U256::zero(),
-            U512::from(2).checked_mul(U512::exp10(9)).unwrap(),
+            token_id,
+            2u8,
+            None,
+            None,
+            0u64,
+            Some(end_time),
         );
+        let price = U512::from(2u8).checked_mul(U512::exp10(9)).unwrap();
+        pre_place_bid(&mut builder, context, account(2), token_id, price, 1u64);
+        settle_auction(&mut builder, context, account(3), token_id, end_time);
 
-        pre_create_offer(
+        let auction: Auction = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", auction);
+        assert_eq!(auction.status, AuctionStatus::Sold);
+        assert_eq!(auction.bids[0].offerer, account(2));
+        assert_eq!(auction.bids[0].status, BidStatus::Accepted);
+    }
+
+    #[test]
+    fn should_place_bid() {
+        let (mut builder, context) = setup();
+        mint_nft(&mut builder, context);
+        approve_nft(&mut builder, context);
+
+        let token_id = U256::zero();
+        create_auction(
+            &mut builder,
+            context,
+            *DEFAULT_ACCOUNT_ADDR,
+            token_id,
+            0u8,
+            None,
+            None,
+            0u64,
+            Some(1653506150007u64),
+        );
+        let first_price = U512::from(2u8).checked_mul(U512::exp10(9)).unwrap();
+        pre_place_bid(
             &mut builder,
             context,
             account(2),
-            U256::zero(),
-            U512::from(3).checked_mul(U512::exp10(9)).unwrap(),
+            token_id,
+            first_price,
+            1u64,
         );
-        mint_nft(&mut builder, context);
-        approve_nft(&mut builder, context);
-        accept_offer(&mut builder, context, U256::zero(), 1u8);
+        let second_price = U512::from(3u8).checked_mul(U512::exp10(9)).unwrap();
+        pre_place_bid(
+            &mut builder,
+            context,
+            account(3),
+            token_id,
+            second_price,
+            2u64,
+        );
+
+        let auction: Auction = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", auction);
+        assert!(auction.bids.len() == 2);
+        assert_eq!(auction.bids[0].status, BidStatus::NotAccepted);
+        assert_eq!(auction.bids[1].price, second_price);
+        assert_eq!(auction.bids[1].status, BidStatus::Pending);
     }
 
     #[test]
-    fn should_install_contract() {
-        let _ = setup();
+    fn should_not_place_bid_below_min_increment() {
+        let (mut builder, context) = setup();
+        mint_nft(&mut builder, context);
+        approve_nft(&mut builder, context);
+
+        let token_id = U256::zero();
+        create_auction(
+            &mut builder,
+            context,
+            *DEFAULT_ACCOUNT_ADDR,
+            token_id,
+            0u8,
+            None,
+            None,
+            0u64,
+            Some(1653506150007u64),
+        );
+        let price = U512::from(2u8).checked_mul(U512::exp10(9)).unwrap();
+        pre_place_bid(&mut builder, context, account(2), token_id, price, 1u64);
+
+        // Beats the highest bid, but by less than the default 1% increment
+        let exec_request = ExecuteRequestBuilder::standard(
+            account(3),
+            PRE_PLACE_BID_CONTRACT_WASM,
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => token_id,
+                AMOUNT_RUNTIME_ARG_NAME => price + U512::one(),
+                "commitment" => Option::<[u8; 32]>::None,
+                MARKETPLACE_CONTRACT_HASH_ARG_NAME => Key::from(context.marketplace_contract)
+            },
+        )
+        .with_block_time(2u64)
+        .build();
+        expect_revert(&mut builder, exec_request, BID_INCREMENT_TOO_LOW_ERROR);
     }
 
     #[test]
-    fn should_transfer_ownership() {
-        // let (mut builder, context) = setup();
+    fn should_raise_bid() {
+        let (mut builder, context) = setup();
+        mint_nft(&mut builder, context);
+        approve_nft(&mut builder, context);
+
+        let token_id = U256::zero();
+        create_auction(
+            &mut builder,
+            context,
+            *DEFAULT_ACCOUNT_ADDR,
+            token_id,
+            0u8,
+            None,
+            None,
+            0u64,
+            Some(1653506150007u64),
+        );
+        let price = U512::from(2u8).checked_mul(U512::exp10(9)).unwrap();
+        pre_place_bid(&mut builder, context, account(2), token_id, price, 1u64);
+        // Only the difference is deposited for the raise
+        let difference = U512::from(1u8).checked_mul(U512::exp10(9)).unwrap();
+        pre_place_bid(
+            &mut builder,
+            context,
+            account(2),
+            token_id,
+            difference,
+            2u64,
+        );
+
+        let auction: Auction = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", auction);
+        assert_eq!(auction.bids[0].status, BidStatus::Canceled);
+        assert_eq!(auction.bids[1].price, price + difference);
+        assert_eq!(auction.bids[1].status, BidStatus::Pending);
     }
 
     #[test]
-    fn should_mint_nft() {
+    fn should_settle_auction_with_bids() {
         let (mut builder, context) = setup();
         mint_nft(&mut builder, context);
+        approve_nft(&mut builder, context);
+
+        let token_id = U256::zero();
+        let end_time = 1653506150007u64;
+        create_auction(
+            &mut builder,
+            context,
+            *DEFAULT_ACCOUNT_ADDR,
+            token_id,
+            0u8,
+            None,
+            None,
+            0u64,
+            Some(end_time),
+        );
+        let price = U512::from(2u8).checked_mul(U512::exp10(9)).unwrap();
+        pre_place_bid(&mut builder, context, account(2), token_id, price, 1u64);
+        settle_auction(&mut builder, context, account(2), token_id, end_time);
+
+        let auction: Auction = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", auction);
+        assert_eq!(auction.status, AuctionStatus::Sold);
+        assert_eq!(auction.bids[0].status, BidStatus::Accepted);
     }
 
     #[test]
-    fn should_approve_nft() {
+    fn should_buy_decreasing_auction() {
         let (mut builder, context) = setup();
         mint_nft(&mut builder, context);
-
         approve_nft(&mut builder, context);
+
+        let token_id = U256::zero();
+        let price = U512::from(10u8).checked_mul(U512::exp10(9)).unwrap();
+        let floor_price = U512::from(2u8).checked_mul(U512::exp10(9)).unwrap();
+        create_auction(
+            &mut builder,
+            context,
+            *DEFAULT_ACCOUNT_ADDR,
+            token_id,
+            3u8,
+            Some(price),
+            Some(floor_price),
+            0u64,
+            Some(1653506150007u64),
+        );
+        pre_place_bid(&mut builder, context, account(2), token_id, price, 1u64);
+
+        let auction: Auction = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", auction);
+        assert_eq!(auction.status, AuctionStatus::Sold);
     }
 
     #[test]
-    fn should_transfer_from_nft() {
+    fn should_index_auction() {
         let (mut builder, context) = setup();
         mint_nft(&mut builder, context);
         approve_nft(&mut builder, context);
+
+        let token_id = U256::zero();
+        let end_time = 1653506150007u64;
+        create_auction(
+            &mut builder,
+            context,
+            *DEFAULT_ACCOUNT_ADDR,
+            token_id,
+            0u8,
+            None,
+            None,
+            0u64,
+            Some(end_time),
+        );
+        let on_auctions: Vec<(ContractHash, U256, (AccountHash, u64))> =
+            builder.get_value(context.marketplace_contract, "on_auctions");
+        assert_eq!(
+            on_auctions,
+            vec![(
+                context.nft_contract_hash,
+                token_id,
+                (*DEFAULT_ACCOUNT_ADDR, end_time)
+            )]
+        );
+
+        settle_auction(&mut builder, context, account(2), token_id, end_time);
+        let on_auctions: Vec<(ContractHash, U256, (AccountHash, u64))> =
+            builder.get_value(context.marketplace_contract, "on_auctions");
+        assert!(on_auctions.is_empty());
     }
 
     #[test]
-    fn should_create_order() {
+    fn should_get_auctions() {
         let (mut builder, context) = setup();
-
         mint_nft(&mut builder, context);
+        call_contract(
+            &mut builder,
+            context.nft_contract_hash,
+            *DEFAULT_ACCOUNT_ADDR,
+            "approve",
+            runtime_args! {
+                "spender" => Key::from(context.marketplace_contract_package),
+                "token_ids" => vec![U256::from(0), U256::from(1)],
+            },
+        );
+        call_contract(
+            &mut builder,
+            context.nft_contract_hash,
+            *DEFAULT_ACCOUNT_ADDR,
+            "transfer",
+            runtime_args! {
+                "recipient" => Key::from(account(2)),
+                "token_ids" => vec![U256::from(2)],
+            },
+        );
+        call_contract(
+            &mut builder,
+            context.nft_contract_hash,
+            account(2),
+            "approve",
+            runtime_args! {
+                "spender" => Key::from(context.marketplace_contract_package),
+                "token_ids" => vec![U256::from(2)],
+            },
+        );
 
-        approve_nft(&mut builder, context);
+        let end_time = 1653506150007u64;
+        for (offerer, token_id, end_time) in vec![
+            (*DEFAULT_ACCOUNT_ADDR, 0u8, end_time + 2_000),
+            (*DEFAULT_ACCOUNT_ADDR, 1u8, end_time),
+            (account(2), 2u8, end_time + 1_000),
+        ] {
+            create_auction(
+                &mut builder,
+                context,
+                offerer,
+                U256::from(token_id),
+                0u8,
+                None,
+                None,
+                0u64,
+                Some(end_time),
+            );
+        }
 
-        create_order(
+        let token_ids = |auctions: Vec<Auction>| -> Vec<U256> {
+            auctions.iter().map(|auction| auction.token_id).collect()
+        };
+
+        let auctions = get_auctions(
             &mut builder,
             context,
-            U256::zero(),
-            U512::from(1000).checked_mul(U512::exp10(9)).unwrap(),
+            "get_auctions_by_collection",
+            Some(Key::from(context.nft_contract_hash)),
+            None,
+            0u64,
+            10u64,
+        );
+        assert_eq!(auctions.len(), 3);
+
+        let auctions = get_auctions(
+            &mut builder,
+            context,
+            "get_auctions_by_seller",
+            None,
+            Some(Key::from(*DEFAULT_ACCOUNT_ADDR)),
+            0u64,
+            10u64,
+        );
+        assert_eq!(token_ids(auctions), vec![U256::from(0), U256::from(1)]);
+
+        let auctions = get_auctions(
+            &mut builder,
+            context,
+            "get_auctions_by_end_time",
+            None,
+            None,
+            0u64,
+            10u64,
+        );
+        assert_eq!(
+            token_ids(auctions),
+            vec![U256::from(1), U256::from(2), U256::from(0)]
+        );
+
+        // Second page of one
+        let auctions = get_auctions(
+            &mut builder,
+            context,
+            "get_auctions_by_end_time",
+            None,
+            None,
+            1u64,
+            1u64,
         );
+        assert_eq!(token_ids(auctions), vec![U256::from(2)]);
     }
 
     #[test]
-    fn should_buy_order() {
+    fn should_cancel_auction() {
         let (mut builder, context) = setup();
-
         mint_nft(&mut builder, context);
-
         approve_nft(&mut builder, context);
 
-        create_order(
+        let token_id = U256::zero();
+        create_auction(
             &mut builder,
             context,
-            U256::zero(),
-            U512::from(1000).checked_mul(U512::exp10(9)).unwrap(),
+            *DEFAULT_ACCOUNT_ADDR,
+            token_id,
+            0u8,
+            None,
+            None,
+            0u64,
+            Some(1653506150007u64),
         );
-        pre_buy_order(&mut builder, context, U256::zero());
+        call_contract(
+            &mut builder,
+            context.marketplace_contract,
+            *DEFAULT_ACCOUNT_ADDR,
+            "cancel_auction",
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => token_id,
+            },
+        );
+
+        let auction: Auction = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", auction);
+        assert_eq!(auction.status, AuctionStatus::Canceled);
+    }
+
+    #[test]
+    fn should_force_cancel_auction() {
+        let (mut builder, context) = setup();
+        mint_nft(&mut builder, context);
+        approve_nft(&mut builder, context);
+
+        let token_id = U256::zero();
+        create_auction(
+            &mut builder,
+            context,
+            *DEFAULT_ACCOUNT_ADDR,
+            token_id,
+            0u8,
+            None,
+            None,
+            0u64,
+            Some(1653506150007u64),
+        );
+        let admin = account(1);
+        authorize_account(&mut builder, context, admin);
+        call_contract(
+            &mut builder,
+            context.marketplace_contract,
+            admin,
+            "force_cancel_auction",
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => token_id,
+            },
+        );
+
+        let auction: Auction = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", auction);
+        assert_eq!(auction.status, AuctionStatus::Canceled);
+    }
+
+    #[test]
+    fn should_create_decreasing_auction() {
+        let (mut builder, context) = setup();
+        mint_nft(&mut builder, context);
+        approve_nft(&mut builder, context);
+
+        let price = U512::from(10u8).checked_mul(U512::exp10(9)).unwrap();
+        let floor_price = U512::from(2u8).checked_mul(U512::exp10(9)).unwrap();
+        create_auction(
+            &mut builder,
+            context,
+            *DEFAULT_ACCOUNT_ADDR,
+            U256::zero(),
+            3u8,
+            Some(price),
+            Some(floor_price),
+            0u64,
+            Some(1653506150007u64),
+        );
+        let auction: Auction = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", auction);
+        assert_eq!(auction.price, Some(price));
+        assert_eq!(auction.floor_price, Some(floor_price));
+    }
+
+    #[test]
+    fn should_create_fixed_price_auction() {
+        let (mut builder, context) = setup();
+        mint_nft(&mut builder, context);
+        approve_nft(&mut builder, context);
+
+        let buy_now_price = U512::from(5u8).checked_mul(U512::exp10(9)).unwrap();
+        create_auction(
+            &mut builder,
+            context,
+            *DEFAULT_ACCOUNT_ADDR,
+            U256::zero(),
+            1u8,
+            Some(buy_now_price),
+            None,
+            0u64,
+            Some(1653506150007u64),
+        );
+        let auction: Auction = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", auction);
+        assert_eq!(auction.price, Some(buy_now_price));
+        assert_eq!(auction.status, AuctionStatus::Active);
+    }
+
+    #[test]
+    fn should_buy_fixed_price_auction_now() {
+        let (mut builder, context) = setup();
+        mint_nft(&mut builder, context);
+        approve_nft(&mut builder, context);
+
+        let token_id = U256::zero();
+        let buy_now_price = U512::from(5u8).checked_mul(U512::exp10(9)).unwrap();
+        create_auction(
+            &mut builder,
+            context,
+            *DEFAULT_ACCOUNT_ADDR,
+            token_id,
+            1u8,
+            Some(buy_now_price),
+            None,
+            0u64,
+            Some(1653506150007u64),
+        );
+        let price = U512::from(2u8).checked_mul(U512::exp10(9)).unwrap();
+        pre_place_bid(&mut builder, context, account(2), token_id, price, 1u64);
+        pre_place_bid(
+            &mut builder,
+            context,
+            account(3),
+            token_id,
+            buy_now_price,
+            2u64,
+        );
+
+        let auction: Auction = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", auction);
+        assert_eq!(auction.status, AuctionStatus::Sold);
+        assert_eq!(auction.bids[0].status, BidStatus::NotAccepted);
+        assert_eq!(auction.bids[1].offerer, account(3));
+        assert_eq!(auction.bids[1].price, buy_now_price);
+        assert_eq!(auction.bids[1].status, BidStatus::Accepted);
+        // The outbid escrow was refunded and the sale paid out
+        let purse_balance: U512 = builder.get_value(context.marketplace_contract, "purse_balance");
+        assert_eq!(purse_balance, U512::zero());
+    }
+
+    #[test]
+    fn should_create_sealed_auction() {
+        let (mut builder, context) = setup();
+        mint_nft(&mut builder, context);
+        approve_nft(&mut builder, context);
+
+        let end_time = 1653506150007u64;
+        let reveal_end_time = end_time + 86_400_000u64;
+        call_contract(
+            &mut builder,
+            context.marketplace_contract,
+            *DEFAULT_ACCOUNT_ADDR,
+            "create_auction",
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => U256::zero(),
+                "auction_type" => 4u8,
+                "price" => Option::<U512>::None,
+                "floor_price" => Option::<U512>::None,
+                "start_time" => 0u64,
+                "end_time" => Some(end_time),
+                "reveal_end_time" => Some(reveal_end_time),
+                "second_price" => true,
+                "extension_window" => Option::<u64>::None,
+                "extension_duration" => Option::<u64>::None,
+                "min_bid_increment" => Option::<U512>::None,
+                "min_bid_increment_rate" => Option::<U512>::None
+            },
+        );
+        let auction: Auction = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", auction);
+        assert_eq!(auction.reveal_end_time, Some(reveal_end_time));
+        assert!(auction.second_price);
+        assert!(auction.sealed_bids.is_empty());
+    }
+
+    #[test]
+    fn should_settle_first_price_sealed_auction() {
+        let (auction, low_refund, high_refund, unrevealed_refund) = run_sealed_auction(false);
+
+        // The winner pays their own bid
+        let high_price = U512::from(4u8).checked_mul(U512::exp10(9)).unwrap();
+        assert_eq!(auction.status, AuctionStatus::Sold);
+        assert_eq!(auction.sealed_bids[0].status, BidStatus::NotAccepted);
+        assert_eq!(auction.sealed_bids[1].status, BidStatus::Accepted);
+        assert_eq!(auction.sealed_bids[2].status, BidStatus::NotAccepted);
+        assert_eq!(auction.sealed_bids[2].price, None);
+        let winning_bid = auction.bids.last().unwrap();
+        assert_eq!(winning_bid.offerer, account(3));
+        assert_eq!(winning_bid.price, high_price);
+        assert_eq!(winning_bid.status, BidStatus::Accepted);
+
+        assert_eq!(
+            low_refund,
+            U512::from(5u8).checked_mul(U512::exp10(9)).unwrap()
+        );
+        assert_eq!(
+            high_refund,
+            U512::from(1u8).checked_mul(U512::exp10(9)).unwrap()
+        );
+        assert_eq!(
+            unrevealed_refund,
+            U512::from(2u8).checked_mul(U512::exp10(9)).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_settle_second_price_sealed_auction() {
+        let (auction, low_refund, high_refund, unrevealed_refund) = run_sealed_auction(true);
+
+        // The winner pays the second highest revealed bid
+        let low_price = U512::from(3u8).checked_mul(U512::exp10(9)).unwrap();
+        assert_eq!(auction.status, AuctionStatus::Sold);
+        assert_eq!(auction.sealed_bids[1].status, BidStatus::Accepted);
+        let winning_bid = auction.bids.last().unwrap();
+        assert_eq!(winning_bid.offerer, account(3));
+        assert_eq!(winning_bid.price, low_price);
+        assert_eq!(winning_bid.status, BidStatus::Accepted);
+
+        assert_eq!(
+            low_refund,
+            U512::from(5u8).checked_mul(U512::exp10(9)).unwrap()
+        );
+        assert_eq!(
+            high_refund,
+            U512::from(2u8).checked_mul(U512::exp10(9)).unwrap()
+        );
+        assert_eq!(
+            unrevealed_refund,
+            U512::from(2u8).checked_mul(U512::exp10(9)).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_settle_second_price_sealed_auction_with_one_bidder() {
+        let (mut builder, context) = setup();
+        mint_nft(&mut builder, context);
+        approve_nft(&mut builder, context);
+
+        let token_id = U256::zero();
+        let end_time = 1653506150007u64;
+        let reveal_end_time = end_time + 86_400_000u64;
+        create_sealed_auction(
+            &mut builder,
+            context,
+            token_id,
+            end_time,
+            reveal_end_time,
+            true,
+            None,
+        );
+
+        let deposit = U512::from(5u8).checked_mul(U512::exp10(9)).unwrap();
+        let price = U512::from(3u8).checked_mul(U512::exp10(9)).unwrap();
+        commit_bid(
+            &mut builder,
+            context,
+            account(2),
+            token_id,
+            deposit,
+            make_commitment(account(2), price, "alone"),
+            1u64,
+        );
+        reveal_bid(
+            &mut builder,
+            context,
+            account(2),
+            token_id,
+            price,
+            "alone",
+            end_time,
+        );
+
+        let before = balance_of(&builder, account(2));
+        settle_auction(&mut builder, context, account(5), token_id, reveal_end_time);
+        let after = balance_of(&builder, account(2));
+
+        // Without a rival bid or a reserve the lone bidder pays nothing
+        let auction: Auction = get_test_result(&mut builder, context.marketplace_contract);
+        assert_eq!(auction.status, AuctionStatus::Sold);
+        let winning_bid = auction.bids.last().unwrap();
+        assert_eq!(winning_bid.offerer, account(2));
+        assert_eq!(winning_bid.price, U512::zero());
+        assert_eq!(after - before, deposit);
+    }
+
+    #[test]
+    fn should_not_commit_bid_below_reserve() {
+        let (mut builder, context) = setup();
+        mint_nft(&mut builder, context);
+        approve_nft(&mut builder, context);
+
+        let token_id = U256::zero();
+        let end_time = 1653506150007u64;
+        let reserve = U512::from(3u8).checked_mul(U512::exp10(9)).unwrap();
+        create_sealed_auction(
+            &mut builder,
+            context,
+            token_id,
+            end_time,
+            end_time + 86_400_000u64,
+            false,
+            Some(reserve),
+        );
+
+        let deposit = U512::from(2u8).checked_mul(U512::exp10(9)).unwrap();
+        let exec_request = ExecuteRequestBuilder::standard(
+            account(2),
+            PRE_PLACE_BID_CONTRACT_WASM,
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => token_id,
+                AMOUNT_RUNTIME_ARG_NAME => deposit,
+                "commitment" => Some(make_commitment(account(2), deposit, "short")),
+                MARKETPLACE_CONTRACT_HASH_ARG_NAME => Key::from(context.marketplace_contract)
+            },
+        )
+        .with_block_time(1u64)
+        .build();
+        expect_revert(&mut builder, exec_request, NOT_VALID_AMOUNT_ERROR);
+    }
+
+    #[test]
+    fn should_set_treasury_wallet() {
+        let (mut builder, context) = setup();
+        let admin = account(1);
+        let treasury_wallet = account(1);
+        authorize_account(&mut builder, context, admin);
+        set_treasury_wallet(
+            &mut builder,
+            context,
+            admin,
+            treasury_wallet.to_formatted_string(),
+        );
+    }
+
+    #[test]
+    fn should_set_fee() {
+        let (mut builder, context) = setup();
+        let admin = account(1);
+        authorize_account(&mut builder, context, admin);
+        set_fee(&mut builder, context, admin, U512::from(25));
+    }
+
+    #[test]
+    fn should_set_anti_sniping() {
+        let (mut builder, context) = setup();
+        let admin = account(1);
+        authorize_account(&mut builder, context, admin);
+        call_contract(
+            &mut builder,
+            context.marketplace_contract,
+            admin,
+            "set_anti_sniping",
+            runtime_args! {
+                "extension_window" => 300_000u64,
+                "extension_duration" => 120_000u64,
+            },
+        );
+    }
+
+    #[test]
+    fn should_set_min_bid_increment() {
+        let (mut builder, context) = setup();
+        let admin = account(1);
+        authorize_account(&mut builder, context, admin);
+        call_contract(
+            &mut builder,
+            context.marketplace_contract,
+            admin,
+            "set_min_bid_increment",
+            runtime_args! {
+                "min_bid_increment" => U512::exp10(9),
+                "min_bid_increment_rate" => U512::from(50u8),
+            },
+        );
+    }
+
+    #[test]
+    fn should_create_offer() {
+        let (mut builder, context) = setup();
+        pre_create_offer(
+            &mut builder,
+            context,
+            *DEFAULT_ACCOUNT_ADDR,
+            U256::zero(),
+            U512::from(2).checked_mul(U512::exp10(9)).unwrap(),
+        );
+
+        pre_create_offer(
+            &mut builder,
+            context,
+            account(2),
+            U256::zero(),
+            U512::from(3).checked_mul(U512::exp10(9)).unwrap(),
+        );
+        let offer: Offer = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", offer);
+        assert!(offer.bids.len() == 2);
+    }
+
+    #[test]
+    fn should_cancel_offer() {
+        let (mut builder, context) = setup();
+        pre_create_offer(
+            &mut builder,
+            context,
+            *DEFAULT_ACCOUNT_ADDR,
+            U256::zero(),
+            U512::from(2).checked_mul(U512::exp10(9)).unwrap(),
+        );
+
+        pre_create_offer(
+            &mut builder,
+            context,
+            account(2),
+            U256::zero(),
+            U512::from(3).checked_mul(U512::exp10(9)).unwrap(),
+        );
+
+        cancel_offer(&mut builder, context, account(2), U256::zero());
+
+        let offer: Offer = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", offer);
+        assert!(offer.bids.len() == 1);
+    }
+
+    #[test]
+    fn should_accept_offer() {
+        let (mut builder, context) = setup();
+        pre_create_offer(
+            &mut builder,
+            context,
+            *DEFAULT_ACCOUNT_ADDR,
+            U256::zero(),
+            U512::from(2).checked_mul(U512::exp10(9)).unwrap(),
+        );
+
+        pre_create_offer(
+            &mut builder,
+            context,
+            account(2),
+            U256::zero(),
+            U512::from(3).checked_mul(U512::exp10(9)).unwrap(),
+        );
+        mint_nft(&mut builder, context);
+        approve_nft(&mut builder, context);
+        accept_offer(
+            &mut builder,
+            context,
+            U256::zero(),
+            account(2),
+            U512::from(3).checked_mul(U512::exp10(9)).unwrap(),
+        );
+
+        let offer: Offer = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", offer);
+        assert_eq!(offer.bids.len(), 1);
+        assert_eq!(offer.bids[0].offerer, *DEFAULT_ACCOUNT_ADDR);
+    }
+
+    #[test]
+    fn should_reject_offer() {
+        let (mut builder, context) = setup();
+        pre_create_offer(
+            &mut builder,
+            context,
+            account(2),
+            U256::zero(),
+            U512::from(3).checked_mul(U512::exp10(9)).unwrap(),
+        );
+        mint_nft(&mut builder, context);
+
+        call_contract(
+            &mut builder,
+            context.marketplace_contract,
+            *DEFAULT_ACCOUNT_ADDR,
+            "reject_offer",
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => U256::zero(),
+                BIDDER_RUNTIME_ARG_NAME => account(2)
+            },
+        );
+
+        let offer: Offer = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", offer);
+        assert_eq!(offer.bids[0].status, BidStatus::NotAccepted);
+    }
+
+    #[test]
+    fn should_accept_collection_offer() {
+        let (mut builder, context) = setup();
+
+        let price = U512::from(2).checked_mul(U512::exp10(9)).unwrap();
+        pre_create_collection_offer(&mut builder, context, account(2), price, 2, BTreeMap::new());
+
+        mint_nft(&mut builder, context);
+        call_contract(
+            &mut builder,
+            context.nft_contract_hash,
+            *DEFAULT_ACCOUNT_ADDR,
+            "approve",
+            runtime_args! {
+                "spender" => Key::from(context.marketplace_contract_package),
+                "token_ids" => vec![U256::from(0), U256::from(1)],
+            },
+        );
+
+        for token_id in vec![U256::from(0), U256::from(1)] {
+            call_contract(
+                &mut builder,
+                context.marketplace_contract,
+                *DEFAULT_ACCOUNT_ADDR,
+                "accept_collection_offer",
+                runtime_args! {
+                    "collection_offer_id" => 0u64,
+                    TOKEN_ID_RUNTIME_ARG_NAME => token_id,
+                },
+            );
+        }
+
+        let collection_offer: CollectionOffer =
+            get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", collection_offer);
+        assert_eq!(collection_offer.filled, 2);
+        assert!(!collection_offer.is_active);
+    }
+
+    #[test]
+    fn should_accept_trait_offer() {
+        let (mut builder, context) = setup();
+
+        let price = U512::from(2).checked_mul(U512::exp10(9)).unwrap();
+        pre_create_collection_offer(
+            &mut builder,
+            context,
+            account(2),
+            price,
+            1,
+            meta::gold_dragon(),
+        );
+
+        mint_nft(&mut builder, context);
+        call_contract(
+            &mut builder,
+            context.nft_contract_hash,
+            *DEFAULT_ACCOUNT_ADDR,
+            "approve",
+            runtime_args! {
+                "spender" => Key::from(context.marketplace_contract_package),
+                "token_ids" => vec![U256::from(0), U256::from(3)],
+            },
+        );
+
+        // Token 0 is a red dragon
+        let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            context.marketplace_contract,
+            "accept_collection_offer",
+            runtime_args! {
+                "collection_offer_id" => 0u64,
+                TOKEN_ID_RUNTIME_ARG_NAME => U256::from(0),
+            },
+        )
+        .build();
+        builder.exec(exec_request).expect_failure().commit();
+
+        call_contract(
+            &mut builder,
+            context.marketplace_contract,
+            *DEFAULT_ACCOUNT_ADDR,
+            "accept_collection_offer",
+            runtime_args! {
+                "collection_offer_id" => 0u64,
+                TOKEN_ID_RUNTIME_ARG_NAME => U256::from(3),
+            },
+        );
+
+        let collection_offer: CollectionOffer =
+            get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", collection_offer);
+        assert_eq!(collection_offer.filled, 1);
+        assert_eq!(collection_offer.traits, meta::gold_dragon());
+    }
+
+    #[test]
+    fn should_reclaim_expired_offers() {
+        let (mut builder, context) = setup();
+
+        let expires_at = 1653506150007u64;
+        let exec_request = ExecuteRequestBuilder::standard(
+            account(2),
+            PER_CREATE_OFFER_CONTRACT_WASM,
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => U256::zero(),
+                AMOUNT_RUNTIME_ARG_NAME => U512::from(3).checked_mul(U512::exp10(9)).unwrap(),
+                "expires_at" => Some(expires_at),
+                MARKETPLACE_CONTRACT_HASH_ARG_NAME => Key::from(context.marketplace_contract)
+            },
+        )
+        .build();
+        builder.exec(exec_request).expect_success().commit();
+
+        pre_create_offer(
+            &mut builder,
+            context,
+            *DEFAULT_ACCOUNT_ADDR,
+            U256::zero(),
+            U512::from(2).checked_mul(U512::exp10(9)).unwrap(),
+        );
+
+        let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+            account(3),
+            context.marketplace_contract,
+            "reclaim_expired_offers",
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => U256::zero(),
+            },
+        )
+        .with_block_time(expires_at)
+        .build();
+        builder.exec(exec_request).expect_success().commit();
+
+        let offer: Offer = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", offer);
+        assert_eq!(offer.bids[0].status, BidStatus::Canceled);
+        assert_eq!(offer.bids[0].expires_at, Some(expires_at));
+        assert_eq!(offer.bids[1].status, BidStatus::Pending);
+    }
+
+    #[test]
+    fn should_install_contract() {
+        let _ = setup();
+    }
+
+    #[test]
+    fn should_transfer_ownership() {
+        // let (mut builder, context) = setup();
+    }
+
+    #[test]
+    fn should_mint_nft() {
+        let (mut builder, context) = setup();
+        mint_nft(&mut builder, context);
+    }
+
+    #[test]
+    fn should_approve_nft() {
+        let (mut builder, context) = setup();
+        mint_nft(&mut builder, context);
+
+        approve_nft(&mut builder, context);
+    }
+
+    #[test]
+    fn should_transfer_from_nft() {
+        let (mut builder, context) = setup();
+        mint_nft(&mut builder, context);
+        approve_nft(&mut builder, context);
+    }
+
+    #[test]
+    fn should_create_order() {
+        let (mut builder, context) = setup();
+
+        mint_nft(&mut builder, context);
+
+        approve_nft(&mut builder, context);
+
+        create_order(
+            &mut builder,
+            context,
+            U256::zero(),
+            U512::from(1000).checked_mul(U512::exp10(9)).unwrap(),
+        );
+    }
+
+    #[test]
+    fn should_buy_order() {
+        let (mut builder, context) = setup();
+
+        mint_nft(&mut builder, context);
+
+        approve_nft(&mut builder, context);
+
+        create_order(
+            &mut builder,
+            context,
+            U256::zero(),
+            U512::from(1000).checked_mul(U512::exp10(9)).unwrap(),
+        );
+        let buyer = account(2);
+        pre_buy_order(&mut builder, context, buyer, U256::zero());
+
+        let order: Order = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", order);
+        assert!(!order.is_active);
+        assert_eq!(order.buyer, Some(buyer));
+        assert!(order.sold_at.is_some());
+    }
+
+    #[test]
+    fn should_change_order_price() {
+        let (mut builder, context) = setup();
+
+        mint_nft(&mut builder, context);
+
+        approve_nft(&mut builder, context);
+
+        create_order(
+            &mut builder,
+            context,
+            U256::zero(),
+            U512::from(1000).checked_mul(U512::exp10(9)).unwrap(),
+        );
+        let price = U512::from(800).checked_mul(U512::exp10(9)).unwrap();
+        call_contract(
+            &mut builder,
+            context.marketplace_contract,
+            *DEFAULT_ACCOUNT_ADDR,
+            "change_order_price",
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => U256::zero(),
+                PRICE_RUNTIME_ARG_NAME => price,
+            },
+        );
+
+        let order: Order = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", order);
+        assert_eq!(order.price, price);
+    }
+
+    #[test]
+    fn should_not_change_order_price_to_zero() {
+        let (mut builder, context) = setup();
+
+        mint_nft(&mut builder, context);
+
+        approve_nft(&mut builder, context);
+
+        create_order(
+            &mut builder,
+            context,
+            U256::zero(),
+            U512::from(1000).checked_mul(U512::exp10(9)).unwrap(),
+        );
+        let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            context.marketplace_contract,
+            "change_order_price",
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => U256::zero(),
+                PRICE_RUNTIME_ARG_NAME => U512::zero(),
+            },
+        )
+        .build();
+        expect_revert(&mut builder, exec_request, NOT_VALID_AMOUNT_ERROR);
+    }
+
+    #[test]
+    fn should_not_change_expired_order_price() {
+        let (mut builder, context) = setup();
+
+        mint_nft(&mut builder, context);
+
+        approve_nft(&mut builder, context);
+
+        let expires_at = 1653506150007u64;
+        call_contract(
+            &mut builder,
+            context.marketplace_contract,
+            *DEFAULT_ACCOUNT_ADDR,
+            CREATE_ORDER_ENTRY_NAME,
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => U256::zero(),
+                PRICE_RUNTIME_ARG_NAME => U512::from(1000).checked_mul(U512::exp10(9)).unwrap(),
+                "expires_at" => Some(expires_at),
+                "reserved_for" => Option::<AccountHash>::None,
+                "custodial" => true,
+            },
+        );
+        let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+            *DEFAULT_ACCOUNT_ADDR,
+            context.marketplace_contract,
+            "change_order_price",
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => U256::zero(),
+                PRICE_RUNTIME_ARG_NAME => U512::from(800).checked_mul(U512::exp10(9)).unwrap(),
+            },
+        )
+        .with_block_time(expires_at)
+        .build();
+        expect_revert(&mut builder, exec_request, ORDER_EXPIRED_ERROR);
+    }
+
+    #[test]
+    fn should_not_buy_order_above_expected_price() {
+        let (mut builder, context) = setup();
+
+        mint_nft(&mut builder, context);
+
+        approve_nft(&mut builder, context);
+
+        create_order(
+            &mut builder,
+            context,
+            U256::zero(),
+            U512::from(800).checked_mul(U512::exp10(9)).unwrap(),
+        );
+        // The seller reprices while the buyer is still expecting the old price
+        call_contract(
+            &mut builder,
+            context.marketplace_contract,
+            *DEFAULT_ACCOUNT_ADDR,
+            "change_order_price",
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => U256::zero(),
+                PRICE_RUNTIME_ARG_NAME => U512::from(1000).checked_mul(U512::exp10(9)).unwrap(),
+            },
+        );
+
+        let exec_request = ExecuteRequestBuilder::standard(
+            account(2),
+            PRE_BUY_ORDER_CONTRACT_WASM,
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => U256::zero(),
+                AMOUNT_RUNTIME_ARG_NAME => U512::from(1000).checked_mul(U512::exp10(9)).unwrap(),
+                "expected_price" => U512::from(800).checked_mul(U512::exp10(9)).unwrap(),
+                MARKETPLACE_CONTRACT_HASH_ARG_NAME => Key::from(context.marketplace_contract)
+            },
+        )
+        .build();
+        expect_revert(&mut builder, exec_request, UNEXPECTED_PRICE_ERROR);
+    }
+
+    #[test]
+    fn should_buy_reserved_order() {
+        let (mut builder, context) = setup();
+
+        mint_nft(&mut builder, context);
+
+        approve_nft(&mut builder, context);
+
+        let buyer = account(2);
+        call_contract(
+            &mut builder,
+            context.marketplace_contract,
+            *DEFAULT_ACCOUNT_ADDR,
+            CREATE_ORDER_ENTRY_NAME,
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => U256::zero(),
+                PRICE_RUNTIME_ARG_NAME => U512::from(1000).checked_mul(U512::exp10(9)).unwrap(),
+                "expires_at" => Option::<u64>::None,
+                "reserved_for" => Some(buyer),
+                "custodial" => true,
+            },
+        );
+
+        // Anyone else is turned away
+        let exec_request = ExecuteRequestBuilder::standard(
+            account(3),
+            PRE_BUY_ORDER_CONTRACT_WASM,
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => U256::zero(),
+                AMOUNT_RUNTIME_ARG_NAME => U512::from(1000).checked_mul(U512::exp10(9)).unwrap(),
+                "expected_price" => U512::from(1000).checked_mul(U512::exp10(9)).unwrap(),
+                MARKETPLACE_CONTRACT_HASH_ARG_NAME => Key::from(context.marketplace_contract)
+            },
+        )
+        .build();
+        expect_revert(&mut builder, exec_request, NOT_RESERVED_BUYER_ERROR);
+
+        pre_buy_order(&mut builder, context, buyer, U256::zero());
+
+        let order: Order = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", order);
+        assert_eq!(order.reserved_for, Some(buyer));
+        assert_eq!(order.buyer, Some(buyer));
+    }
+
+    #[test]
+    fn should_buy_non_custodial_order() {
+        let (mut builder, context) = setup();
+
+        mint_nft(&mut builder, context);
+
+        approve_nft(&mut builder, context);
+
+        create_non_custodial_order(&mut builder, context);
+        let buyer = account(2);
+        pre_buy_order(&mut builder, context, buyer, U256::zero());
+
+        let order: Order = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", order);
+        assert!(!order.custodial);
+        assert!(order.is_valid);
+        assert_eq!(order.buyer, Some(buyer));
+    }
+
+    #[test]
+    fn should_invalidate_non_custodial_order() {
+        let (mut builder, context) = setup();
+
+        mint_nft(&mut builder, context);
+
+        approve_nft(&mut builder, context);
+
+        create_non_custodial_order(&mut builder, context);
+        // The seller moves the token away after listing it
+        call_contract(
+            &mut builder,
+            context.nft_contract_hash,
+            *DEFAULT_ACCOUNT_ADDR,
+            "transfer",
+            runtime_args! {
+                "recipient" => Key::from(account(3)),
+                "token_ids" => vec![U256::zero()],
+            },
+        );
+        pre_buy_order(&mut builder, context, account(2), U256::zero());
+
+        let order: Order = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", order);
+        assert!(!order.is_active);
+        assert!(!order.is_valid);
+        assert_eq!(order.buyer, None);
+    }
+
+    #[test]
+    fn should_remove_expired_order() {
+        let (mut builder, context) = setup();
+
+        mint_nft(&mut builder, context);
+
+        approve_nft(&mut builder, context);
+
+        let expires_at = 1653506150007u64;
+        call_contract(
+            &mut builder,
+            context.marketplace_contract,
+            *DEFAULT_ACCOUNT_ADDR,
+            CREATE_ORDER_ENTRY_NAME,
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => U256::zero(),
+                PRICE_RUNTIME_ARG_NAME => U512::from(1000).checked_mul(U512::exp10(9)).unwrap(),
+                "expires_at" => Some(expires_at),
+                "reserved_for" => Option::<AccountHash>::None,
+                "custodial" => true,
+            },
+        );
+        let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+            account(2),
+            context.marketplace_contract,
+            "remove_expired_order",
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                TOKEN_ID_RUNTIME_ARG_NAME => U256::zero(),
+            },
+        )
+        .with_block_time(expires_at)
+        .build();
+        builder.exec(exec_request).expect_success().commit();
+
+        let order: Order = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", order);
+        assert!(!order.is_active);
+        assert_eq!(order.expires_at, Some(expires_at));
+    }
+
+    #[test]
+    fn should_create_and_cancel_orders() {
+        let (mut builder, context) = setup();
+
+        mint_nft(&mut builder, context);
+
+        let token_ids = vec![U256::from(0), U256::from(1), U256::from(2)];
+        call_contract(
+            &mut builder,
+            context.nft_contract_hash,
+            *DEFAULT_ACCOUNT_ADDR,
+            "approve",
+            runtime_args! {
+                "spender" => Key::from(context.marketplace_contract_package),
+                "token_ids" => token_ids.clone(),
+            },
+        );
+        call_contract(
+            &mut builder,
+            context.marketplace_contract,
+            *DEFAULT_ACCOUNT_ADDR,
+            "create_orders",
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                "token_ids" => token_ids.clone(),
+                "prices" => vec![U512::from(1000).checked_mul(U512::exp10(9)).unwrap(); 3],
+                "expires_at" => Option::<u64>::None,
+            },
+        );
+        let on_orders: Vec<(ContractHash, U256)> =
+            builder.get_value(context.marketplace_contract, "on_orders");
+        assert_eq!(on_orders.len(), 3);
+
+        call_contract(
+            &mut builder,
+            context.marketplace_contract,
+            *DEFAULT_ACCOUNT_ADDR,
+            "cancel_orders",
+            runtime_args! {
+                COLLECTION_RUNTIME_ARG_NAME => Key::from(context.nft_contract_hash),
+                "token_ids" => token_ids,
+            },
+        );
+        let on_orders: Vec<(ContractHash, U256)> =
+            builder.get_value(context.marketplace_contract, "on_orders");
+        assert!(on_orders.is_empty());
+    }
+
+    #[test]
+    fn should_buy_bundle() {
+        let (mut builder, context) = setup();
+
+        mint_nft(&mut builder, context);
+
+        let token_ids = vec![U256::from(0), U256::from(1)];
+        call_contract(
+            &mut builder,
+            context.nft_contract_hash,
+            *DEFAULT_ACCOUNT_ADDR,
+            "approve",
+            runtime_args! {
+                "spender" => Key::from(context.marketplace_contract_package),
+                "token_ids" => token_ids.clone(),
+            },
+        );
+        let price = U512::from(1500).checked_mul(U512::exp10(9)).unwrap();
+        call_contract(
+            &mut builder,
+            context.marketplace_contract,
+            *DEFAULT_ACCOUNT_ADDR,
+            "create_bundle",
+            runtime_args! {
+                "collections" => vec![Key::from(context.nft_contract_hash); 2],
+                "token_ids" => token_ids,
+                PRICE_RUNTIME_ARG_NAME => price,
+            },
+        );
+        let bundle: Bundle = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", bundle);
+        assert!(bundle.tokens.len() == 2);
+
+        let buyer = account(2);
+        pre_buy_bundle(&mut builder, context, buyer, bundle.bundle_id, price);
+
+        let bundle: Bundle = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", bundle);
+        assert!(!bundle.is_active);
+        assert_eq!(bundle.buyer, Some(buyer));
+    }
+
+    #[test]
+    fn should_cancel_bundle() {
+        let (mut builder, context) = setup();
+
+        mint_nft(&mut builder, context);
+
+        let token_ids = vec![U256::from(0), U256::from(1)];
+        let approve = |builder: &mut InMemoryWasmTestBuilder| {
+            call_contract(
+                builder,
+                context.nft_contract_hash,
+                *DEFAULT_ACCOUNT_ADDR,
+                "approve",
+                runtime_args! {
+                    "spender" => Key::from(context.marketplace_contract_package),
+                    "token_ids" => token_ids.clone(),
+                },
+            );
+        };
+        let create_bundle = |builder: &mut InMemoryWasmTestBuilder| {
+            call_contract(
+                builder,
+                context.marketplace_contract,
+                *DEFAULT_ACCOUNT_ADDR,
+                "create_bundle",
+                runtime_args! {
+                    "collections" => vec![Key::from(context.nft_contract_hash); 2],
+                    "token_ids" => token_ids.clone(),
+                    PRICE_RUNTIME_ARG_NAME => U512::from(1500).checked_mul(U512::exp10(9)).unwrap(),
+                },
+            );
+        };
+        approve(&mut builder);
+        create_bundle(&mut builder);
+        let bundle: Bundle = get_test_result(&mut builder, context.marketplace_contract);
+
+        call_contract(
+            &mut builder,
+            context.marketplace_contract,
+            *DEFAULT_ACCOUNT_ADDR,
+            "cancel_bundle",
+            runtime_args! {
+                "bundle_id" => bundle.bundle_id,
+            },
+        );
+        let bundle: Bundle = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", bundle);
+        assert!(!bundle.is_active);
+        assert_eq!(bundle.buyer, None);
+
+        // The offerer holds the tokens again and can bundle them anew
+        approve(&mut builder);
+        create_bundle(&mut builder);
+        let rebundled: Bundle = get_test_result(&mut builder, context.marketplace_contract);
+        assert_eq!(
+            rebundled.tokens,
+            token_ids
+                .into_iter()
+                .map(|token_id| (context.nft_contract_hash, token_id))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_assign_sequential_order_ids() {
+        let (mut builder, context) = setup();
+
+        mint_nft(&mut builder, context);
+
+        let price = U512::from(1000).checked_mul(U512::exp10(9)).unwrap();
+        for order_id in 0..2u64 {
+            approve_nft(&mut builder, context);
+            create_order(&mut builder, context, U256::zero(), price);
+            cancel_order(&mut builder, context, U256::zero());
+
+            let order: Order = get_test_result(&mut builder, context.marketplace_contract);
+            println!("{:?}", order);
+            assert_eq!(order.order_id, order_id);
+        }
+    }
+
+    #[test]
+    fn should_refund_order_overpayment() {
+        let (mut builder, context) = setup();
+
+        mint_nft(&mut builder, context);
+
+        approve_nft(&mut builder, context);
+
+        // pre_buy_order sends 1000 CSPR
+        let price = U512::from(800).checked_mul(U512::exp10(9)).unwrap();
+        create_order(&mut builder, context, U256::zero(), price);
+        let buyer = account(2);
+        pre_buy_order(&mut builder, context, buyer, U256::zero());
+
+        let order: Order = get_test_result(&mut builder, context.marketplace_contract);
+        println!("{:?}", order);
+        assert_eq!(order.buyer, Some(buyer));
+        let purse_balance: U512 = builder.get_value(context.marketplace_contract, "purse_balance");
+        assert_eq!(purse_balance, U512::zero());
+    }
+
+    #[test]
+    fn should_buy_orders() {
+        let (mut builder, context) = setup();
+
+        mint_nft(&mut builder, context);
+
+        call_contract(
+            &mut builder,
+            context.nft_contract_hash,
+            *DEFAULT_ACCOUNT_ADDR,
+            "approve",
+            runtime_args! {
+                "spender" => Key::from(context.marketplace_contract_package),
+                "token_ids" => vec![U256::from(0), U256::from(1)],
+            },
+        );
+        let cspr = U512::exp10(9);
+        create_order(&mut builder, context, U256::from(0), U512::from(800) * cspr);
+        create_order(&mut builder, context, U256::from(1), U512::from(900) * cspr);
+
+        let collection = Key::from(context.nft_contract_hash);
+        // Token 2 was never listed and is skipped
+        pre_buy_orders(
+            &mut builder,
+            context,
+            account(2),
+            vec![
+                (collection, U256::from(0), U512::from(1000) * cspr),
+                (collection, U256::from(1), U512::from(1000) * cspr),
+                (collection, U256::from(2), U512::from(1000) * cspr),
+            ],
+            U512::from(3000) * cspr,
+        );
+
+        let on_orders: Vec<(ContractHash, U256)> =
+            builder.get_value(context.marketplace_contract, "on_orders");
+        assert!(on_orders.is_empty());
+        let purse_balance: U512 = builder.get_value(context.marketplace_contract, "purse_balance");
+        assert_eq!(purse_balance, U512::zero());
     }
 
     #[test]
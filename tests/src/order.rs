@@ -7,11 +7,18 @@ use casper_types::{
 
 #[derive(Clone, Copy, Debug)]
 pub struct Order {
+    pub order_id: u64,
     pub collection: ContractHash,
     pub token_id: U256,
     pub offerer: AccountHash,
     pub price: U512,
     pub is_active: bool,
+    pub buyer: Option<AccountHash>,
+    pub sold_at: Option<u64>,
+    pub expires_at: Option<u64>,
+    pub reserved_for: Option<AccountHash>,
+    pub custodial: bool,
+    pub is_valid: bool,
 }
 
 impl CLTyped for Order {
@@ -25,21 +32,35 @@ impl ToBytes for Order {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut result: Vec<u8> = Vec::new();
 
+        result.append(&mut self.order_id.into_bytes().unwrap());
         result.append(&mut self.collection.into_bytes().unwrap());
         result.append(&mut self.token_id.into_bytes().unwrap());
         result.append(&mut self.offerer.into_bytes().unwrap());
         result.append(&mut self.price.into_bytes().unwrap());
         result.append(&mut self.is_active.into_bytes().unwrap());
+        result.append(&mut self.buyer.into_bytes().unwrap());
+        result.append(&mut self.sold_at.into_bytes().unwrap());
+        result.append(&mut self.expires_at.into_bytes().unwrap());
+        result.append(&mut self.reserved_for.into_bytes().unwrap());
+        result.append(&mut self.custodial.into_bytes().unwrap());
+        result.append(&mut self.is_valid.into_bytes().unwrap());
         Ok(result)
     }
 
     #[inline(always)]
     fn serialized_length(&self) -> usize {
-        self.collection.serialized_length()
+        self.order_id.serialized_length()
+            + self.collection.serialized_length()
             + self.token_id.serialized_length()
             + self.offerer.serialized_length()
             + self.price.serialized_length()
-            + self.price.serialized_length()
+            + self.is_active.serialized_length()
+            + self.buyer.serialized_length()
+            + self.sold_at.serialized_length()
+            + self.expires_at.serialized_length()
+            + self.reserved_for.serialized_length()
+            + self.custodial.serialized_length()
+            + self.is_valid.serialized_length()
     }
 
     fn into_bytes(self) -> Result<Vec<u8>, casper_types::bytesrepr::Error>
@@ -52,19 +73,33 @@ impl ToBytes for Order {
 
 impl FromBytes for Order {
     fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (order_id, bytes) = u64::from_bytes(bytes).unwrap();
         let (collection, bytes) = ContractHash::from_bytes(bytes).unwrap();
         let (token_id, bytes) = U256::from_bytes(bytes).unwrap();
         let (offerer, bytes) = AccountHash::from_bytes(bytes).unwrap();
         let (price, bytes) = U512::from_bytes(bytes).unwrap();
         let (is_active, bytes) = bool::from_bytes(bytes).unwrap();
+        let (buyer, bytes) = Option::<AccountHash>::from_bytes(bytes).unwrap();
+        let (sold_at, bytes) = Option::<u64>::from_bytes(bytes).unwrap();
+        let (expires_at, bytes) = Option::<u64>::from_bytes(bytes).unwrap();
+        let (reserved_for, bytes) = Option::<AccountHash>::from_bytes(bytes).unwrap();
+        let (custodial, bytes) = bool::from_bytes(bytes).unwrap();
+        let (is_valid, bytes) = bool::from_bytes(bytes).unwrap();
 
         Ok((
             Order {
+                order_id,
                 collection,
                 token_id,
                 offerer,
                 price,
                 is_active,
+                buyer,
+                sold_at,
+                expires_at,
+                reserved_for,
+                custodial,
+                is_valid,
             },
             bytes,
         ))